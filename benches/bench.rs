@@ -1,24 +1,21 @@
-#![feature(test)]
-
-
-extern crate test;
+#[macro_use]
+extern crate criterion;
 
 extern crate vector;
-extern crate collection_traits;
 
 
-use test::Bencher;
+use criterion::Criterion;
+#[cfg(feature = "nightly")]
+use criterion::BatchSize;
 
 use vector::Vector;
-use collection_traits::*;
 
 
 const SIZE: usize = 1024;
 
 
-#[bench]
-fn bench_vector(b: &mut Bencher) {
-    b.iter(|| {
+fn bench_vector(c: &mut Criterion) {
+    c.bench_function("vector", |b| b.iter(|| {
         let mut v = Vector::<usize>::new();
         for i in 0..SIZE {
             v.push(i);
@@ -27,11 +24,10 @@ fn bench_vector(b: &mut Bencher) {
             v.pop();
         }
         v
-    });
+    }));
 }
-#[bench]
-fn bench_std_vector(b: &mut Bencher) {
-    b.iter(|| {
+fn bench_std_vector(c: &mut Criterion) {
+    c.bench_function("std_vector", |b| b.iter(|| {
         let mut v = Vec::<usize>::new();
         for i in 0..SIZE {
             v.push(i);
@@ -40,28 +36,72 @@ fn bench_std_vector(b: &mut Bencher) {
             v.pop();
         }
         v
-    });
+    }));
 }
 
-#[bench]
-fn bench_vector_clone(b: &mut Bencher) {
+fn bench_vector_clone(c: &mut Criterion) {
     let mut v = Vector::<usize>::new();
     for i in 0..SIZE {
         v.push(i);
     }
 
-    b.iter(move || {
+    c.bench_function("vector_clone", move |b| b.iter(|| {
         v.clone()
-    });
+    }));
 }
-#[bench]
-fn bench_std_vector_clone(b: &mut Bencher) {
+fn bench_std_vector_clone(c: &mut Criterion) {
     let mut v = Vec::<usize>::new();
     for i in 0..SIZE {
         v.push(i);
     }
 
-    b.iter(move || {
+    c.bench_function("std_vector_clone", move |b| b.iter(|| {
         v.clone()
-    });
+    }));
+}
+
+#[cfg(feature = "nightly")]
+fn bench_vector_collect_in_place(c: &mut Criterion) {
+    c.bench_function("vector_collect_in_place", |b| b.iter_batched(
+        || (0..SIZE).collect::<Vector<usize>>(),
+        |v| v.into_iter().map(|x| x * 2).filter(|x| x % 3 != 0).collect::<Vector<usize>>(),
+        BatchSize::SmallInput,
+    ));
+}
+#[cfg(feature = "nightly")]
+fn bench_vector_collect_new_buffer(c: &mut Criterion) {
+    c.bench_function("vector_collect_new_buffer", |b| b.iter_batched(
+        || (0..SIZE).collect::<Vector<usize>>(),
+        |v| v.iter().map(|x| x * 2).filter(|x| x % 3 != 0).collect::<Vector<usize>>(),
+        BatchSize::SmallInput,
+    ));
 }
+#[cfg(feature = "nightly")]
+fn bench_std_vector_collect_in_place(c: &mut Criterion) {
+    c.bench_function("std_vector_collect_in_place", |b| b.iter_batched(
+        || (0..SIZE).collect::<Vec<usize>>(),
+        |v| v.into_iter().map(|x| x * 2).filter(|x| x % 3 != 0).collect::<Vec<usize>>(),
+        BatchSize::SmallInput,
+    ));
+}
+
+
+criterion_group!(
+    benches,
+    bench_vector,
+    bench_std_vector,
+    bench_vector_clone,
+    bench_std_vector_clone
+);
+#[cfg(feature = "nightly")]
+criterion_group!(
+    collect_benches,
+    bench_vector_collect_in_place,
+    bench_vector_collect_new_buffer,
+    bench_std_vector_collect_in_place
+);
+
+#[cfg(not(feature = "nightly"))]
+criterion_main!(benches);
+#[cfg(feature = "nightly")]
+criterion_main!(benches, collect_benches);
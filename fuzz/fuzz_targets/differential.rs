@@ -0,0 +1,144 @@
+#![no_main]
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use vector::Vector;
+
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Push(u8),
+    Pop,
+    Insert(u8, u8),
+    Remove(u8),
+    Truncate(u8),
+    Retain(u8),
+    Drain(u8, u8),
+    SplitOff(u8),
+    Append(Vec<u8>),
+    Resize(u8, u8),
+    Extend(Vec<u8>),
+    Clone,
+    IntoIter(u8, u8),
+}
+
+
+struct Element {
+    value: u8,
+    live: Rc<Cell<usize>>,
+}
+
+impl Element {
+    fn new(value: u8, live: &Rc<Cell<usize>>) -> Self {
+        live.set(live.get() + 1);
+        Element {
+            value,
+            live: live.clone(),
+        }
+    }
+}
+
+impl Clone for Element {
+    fn clone(&self) -> Self {
+        Element::new(self.value, &self.live)
+    }
+}
+
+impl Drop for Element {
+    fn drop(&mut self) {
+        self.live.set(self.live.get() - 1);
+    }
+}
+
+
+struct Side<V> {
+    items: V,
+    live: Rc<Cell<usize>>,
+}
+
+fn range(len: usize, a: u8, b: u8) -> (usize, usize) {
+    let start = a as usize % (len + 1);
+    let end = start + b as usize % (len - start + 1);
+    (start, end)
+}
+
+fn check(vector: &Side<Vector<Element>>, shadow: &Side<Vec<Element>>) {
+    assert_eq!(vector.items.len(), shadow.items.len());
+    assert!(vector.items.capacity() >= vector.items.len());
+    assert!(vector.items.iter().map(|x| x.value).eq(shadow.items.iter().map(|x| x.value)));
+    assert_eq!(vector.live.get(), vector.items.len());
+    assert_eq!(shadow.live.get(), shadow.items.len());
+}
+
+macro_rules! apply {
+    ($side:expr, $new:expr, $op:expr) => {{
+        let side = &mut $side;
+        let live = side.live.clone();
+        let items = &mut side.items;
+        let len = items.len();
+
+        match *$op {
+            Op::Push(value) => items.push(Element::new(value, &live)),
+            Op::Pop => drop(items.pop()),
+            Op::Insert(index, value) => items.insert(index as usize % (len + 1), Element::new(value, &live)),
+            Op::Remove(index) => if len > 0 {
+                drop(items.remove(index as usize % len));
+            },
+            Op::Truncate(new_len) => items.truncate(new_len as usize),
+            Op::Retain(modulus) => items.retain(|x| x.value % (modulus | 1) != 0),
+            Op::Drain(a, b) => {
+                let (start, end) = range(len, a, b);
+                let drained = items.drain(start..end).take(b as usize % 4).count();
+                assert!(drained <= end - start);
+            },
+            Op::SplitOff(at) => {
+                let mut tail = items.split_off(at as usize % (len + 1));
+                tail.reverse();
+                items.append(&mut tail);
+                assert!(tail.is_empty());
+            },
+            Op::Append(ref values) => {
+                let mut other = $new;
+                other.extend(values.iter().map(|&value| Element::new(value, &live)));
+                items.append(&mut other);
+            },
+            Op::Resize(new_len, value) => items.resize(new_len as usize, Element::new(value, &live)),
+            Op::Extend(ref values) => items.extend(values.iter().map(|&value| Element::new(value, &live))),
+            Op::Clone => {
+                let clone = items.clone();
+                *items = clone;
+            },
+            Op::IntoIter(front, back) => {
+                let mut iter = core::mem::replace(items, $new).into_iter();
+                for _ in 0..front % 8 {
+                    drop(iter.next());
+                }
+                for _ in 0..back % 8 {
+                    drop(iter.next_back());
+                }
+                if front & 8 == 0 {
+                    items.extend(iter);
+                }
+            },
+        }
+    }};
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut vector = Side { items: Vector::new(), live: Rc::new(Cell::new(0)) };
+    let mut shadow = Side { items: Vec::new(), live: Rc::new(Cell::new(0)) };
+
+    for op in &ops {
+        apply!(vector, Vector::new(), op);
+        apply!(shadow, Vec::new(), op);
+        check(&vector, &shadow);
+    }
+
+    drop(vector.items);
+    drop(shadow.items);
+    assert_eq!(vector.live.get(), 0);
+    assert_eq!(shadow.live.get(), 0);
+});
@@ -0,0 +1,789 @@
+use core::{fmt, ptr, slice, mem};
+use core::ops::*;
+use core::ptr::NonNull;
+use core::mem::MaybeUninit;
+use core::hash::{self, Hash};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::iter::{FusedIterator, FromIterator};
+
+use collection_traits::*;
+
+use super::vector::{range_bounds, retain_raw};
+
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    #[inline(always)]
+    pub const fn new(element: T) -> Self {
+        CapacityError {
+            element,
+        }
+    }
+    #[inline(always)]
+    pub fn element(self) -> T {
+        self.element
+    }
+    #[inline(always)]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError::new(())
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CapacityError: insufficient capacity")
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+
+pub struct ArrayVector<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVector<T, N> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        ArrayVector {
+            data: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+    #[inline]
+    pub const fn from_array<const M: usize>(array: [T; M]) -> Self {
+        assert!(M <= N, "array is longer than the ArrayVector capacity");
+
+        let mut vector = Self::new();
+        let array = mem::ManuallyDrop::new(array);
+        unsafe {
+            ptr::copy_nonoverlapping(
+                &array as *const mem::ManuallyDrop<[T; M]> as *const T,
+                vector.data.as_mut_ptr() as *mut T,
+                M
+            );
+        }
+        vector.len = M;
+        vector
+    }
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+    #[inline(always)]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+    #[inline]
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.len == N {
+            return Err(CapacityError::new(element));
+        }
+        unsafe {
+            ptr::write(self.as_mut_ptr().add(self.len), element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        self.try_push(element).unwrap()
+    }
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            unsafe {
+                self.len -= 1;
+                Some(ptr::read(self.as_ptr().add(self.len)))
+            }
+        }
+    }
+    #[inline]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        let len = self.len;
+        assert!(index <= len, "insertion index (is {}) should be <= len (is {})", index, len);
+
+        if len == N {
+            return Err(CapacityError::new(element));
+        }
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), len - index);
+            ptr::write(p, element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.try_insert(index, element).unwrap()
+    }
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "removal index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            let ret = ptr::read(p);
+            ptr::copy(p.add(1), p, len - index - 1);
+            self.len -= 1;
+            ret
+        }
+    }
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "swap_remove index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let p = self.as_mut_ptr();
+            let value = ptr::read(p.add(index));
+            ptr::copy(p.add(len - 1), p.add(index), 1);
+            self.len -= 1;
+            value
+        }
+    }
+    #[inline]
+    pub fn try_append(&mut self, other: &mut Self) -> Result<(), CapacityError> {
+        if other.len > self.remaining_capacity() {
+            return Err(CapacityError::new(()));
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len), other.len);
+            self.len += other.len;
+            other.set_len(0);
+        }
+        Ok(())
+    }
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        self.try_append(other).unwrap()
+    }
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), CapacityError<T>> {
+        for element in iter {
+            self.try_push(element)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+    #[inline(always)]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        self
+    }
+    /// # Safety
+    ///
+    /// `len` must not exceed `N`, and the first `len` elements must be
+    /// initialized.
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), self.len - len);
+            self.len = len;
+            ptr::drop_in_place(tail);
+        }
+    }
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&T) -> bool
+    {
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            retain_raw(ptr, &mut self.len, |element| f(element));
+        }
+    }
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+        where R: RangeBounds<usize>
+    {
+        let len = self.len;
+        let (start, end) = range_bounds(&range, len);
+
+        unsafe {
+            self.set_len(start);
+            let vec = NonNull::from(&mut *self);
+            let range_slice = slice::from_raw_parts(Self::data_ptr(vec.as_ptr()).add(start), end - start);
+            Drain {
+                tail_start: end,
+                tail_len: len - end,
+                iter: range_slice.iter(),
+                vec,
+            }
+        }
+    }
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "`at` out of bounds");
+
+        let other_len = self.len - at;
+        let mut other = Self::new();
+
+        unsafe {
+            self.set_len(at);
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), other_len);
+            other.set_len(other_len);
+        }
+        other
+    }
+    #[inline]
+    pub fn into_inner(self) -> Result<[T; N], Self> {
+        if self.len == N {
+            let this = mem::ManuallyDrop::new(self);
+            unsafe {
+                Ok(ptr::read(this.as_ptr() as *const [T; N]))
+            }
+        } else {
+            Err(self)
+        }
+    }
+
+    #[inline(always)]
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+    #[inline(always)]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+    #[inline(always)]
+    unsafe fn data_ptr(this: *mut Self) -> *mut T {
+        ptr::addr_of_mut!((*this).data) as *mut T
+    }
+}
+
+impl<T: Clone, const N: usize> ArrayVector<T, N> {
+    #[inline]
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), CapacityError<T>> {
+        let len = self.len;
+
+        if new_len > N {
+            return Err(CapacityError::new(value));
+        }
+        if new_len > len {
+            for _ in len + 1..new_len {
+                let _ = self.try_push(value.clone());
+            }
+            let _ = self.try_push(value);
+        } else {
+            self.truncate(new_len);
+        }
+        Ok(())
+    }
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.try_resize(new_len, value).unwrap()
+    }
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError> {
+        if other.len() > self.remaining_capacity() {
+            return Err(CapacityError::new(()));
+        }
+        for value in other {
+            let _ = self.try_push(value.clone());
+        }
+        Ok(())
+    }
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.try_extend_from_slice(other).unwrap()
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVector<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVector<T, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(&mut self[..]);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVector<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut vector = Self::new();
+        let _ = vector.try_extend_from_slice(self);
+        vector
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<ArrayVector<U, M>> for ArrayVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &ArrayVector<U, M>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<'b, T: PartialEq<U>, U, const N: usize> PartialEq<&'b [U]> for ArrayVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &&'b [U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for ArrayVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVector<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayVector<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayVector<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayVector<T, N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVector<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr(), self.len)
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVector<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.as_mut_ptr(), self.len)
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>, const N: usize> Index<I> for ArrayVector<T, N> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &I::Output {
+        Index::index(&**self, index)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>, const N: usize> IndexMut<I> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        IndexMut::index_mut(&mut **self, index)
+    }
+}
+
+impl<T, const N: usize> Collection for ArrayVector<T, N> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> CollectionMut for ArrayVector<T, N> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        ArrayVector::clear(self)
+    }
+}
+
+impl<T, const N: usize> InsertMut<usize, T> for ArrayVector<T, N> {
+    type Output = ();
+
+    #[inline(always)]
+    fn insert(&mut self, index: usize, element: T) -> Self::Output {
+        ArrayVector::insert(self, index, element)
+    }
+}
+
+impl<T, const N: usize> RemoveMut<usize> for ArrayVector<T, N> {
+    type Output = T;
+
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        ArrayVector::remove(self, index)
+    }
+}
+
+impl<T, const N: usize> DequeMut<T> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn push_front(&mut self, element: T) {
+        self.insert(0, element);
+    }
+    #[inline(always)]
+    fn push_back(&mut self, element: T) {
+        ArrayVector::push(self, element)
+    }
+    #[inline(always)]
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.remove(0))
+        }
+    }
+    #[inline(always)]
+    fn pop_back(&mut self) -> Option<T> {
+        ArrayVector::pop(self)
+    }
+    #[inline(always)]
+    fn front(&self) -> Option<&T> {
+        self.first()
+    }
+    #[inline(always)]
+    fn back(&self) -> Option<&T> {
+        self.last()
+    }
+    #[inline(always)]
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+    #[inline(always)]
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+}
+
+impl<T, const N: usize> StackMut<T> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn push(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> { self.pop_back() }
+    #[inline(always)]
+    fn top(&self) -> Option<&T> { self.back() }
+    #[inline(always)]
+    fn top_mut(&mut self) -> Option<&mut T> { self.back_mut() }
+}
+
+impl<T, const N: usize> QueueMut<T> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn enqueue(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn dequeue(&mut self) -> Option<T> { self.pop_front() }
+    #[inline(always)]
+    fn peek(&self) -> Option<&T> { self.front() }
+    #[inline(always)]
+    fn peek_mut(&mut self) -> Option<&mut T> { self.front_mut() }
+}
+
+impl<'a, T: 'a, const N: usize> Iterable<'a, &'a T> for ArrayVector<T, N> {
+    type Iter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        (**self).iter()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> IterableMut<'a, &'a mut T> for ArrayVector<T, N> {
+    type IterMut = slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        (**self).iter_mut()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> SeqMut<'a, T> for ArrayVector<T, N> {}
+
+
+impl<T, const N: usize> FromIterator<T> for ArrayVector<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Self::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVector<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap()
+    }
+}
+
+impl<'a, T: 'a + Copy, const N: usize> Extend<&'a T> for ArrayVector<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned())
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVector<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVector<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(mut self) -> IntoIter<T, N> {
+        let end = self.len;
+        self.len = 0;
+        IntoIter {
+            vector: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVector<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVector<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayVector<T, N> {
+    #[inline(always)]
+    fn from(array: [T; N]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<'a, T: Clone, const N: usize> TryFrom<&'a [T]> for ArrayVector<T, N> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: &'a [T]) -> Result<Self, CapacityError> {
+        let mut vector = Self::new();
+        vector.try_extend_from_slice(s)?;
+        Ok(vector)
+    }
+}
+
+
+pub struct IntoIter<T, const N: usize> {
+    vector: ArrayVector<T, N>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.vector.as_ptr().add(self.start), self.end - self.start)
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            let index = self.start;
+            self.start += 1;
+            unsafe {
+                Some(ptr::read(self.vector.as_ptr().add(index)))
+            }
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            unsafe {
+                Some(ptr::read(self.vector.as_ptr().add(self.end)))
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = ptr::slice_from_raw_parts_mut(
+                self.vector.as_mut_ptr().add(self.start),
+                self.end - self.start,
+            );
+            self.start = self.end;
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+
+pub struct Drain<'a, T: 'a, const N: usize> {
+    tail_start: usize,
+    tail_len: usize,
+    iter: slice::Iter<'a, T>,
+    vec: NonNull<ArrayVector<T, N>>,
+}
+
+unsafe impl<'a, T: Sync, const N: usize> Sync for Drain<'a, T, N> {}
+unsafe impl<'a, T: Send, const N: usize> Send for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        struct DropGuard<'r, 'a: 'r, T: 'a, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<'r, 'a, T, const N: usize> Drop for DropGuard<'r, 'a, T, N> {
+            fn drop(&mut self) {
+                if self.0.tail_len > 0 {
+                    unsafe {
+                        let vec = self.0.vec.as_ptr();
+                        let len = ptr::addr_of_mut!((*vec).len);
+                        let start = *len;
+                        let tail = self.0.tail_start;
+                        if tail != start {
+                            let p = ArrayVector::data_ptr(vec);
+                            ptr::copy(p.add(tail), p.add(start), self.0.tail_len);
+                        }
+                        *len = start + self.0.tail_len;
+                    }
+                }
+            }
+        }
+
+        let iter = mem::take(&mut self.iter);
+        let drop_len = iter.len();
+        let vec = self.vec;
+
+        let _guard = DropGuard(self);
+
+        if drop_len == 0 {
+            return;
+        }
+
+        unsafe {
+            let vec_ptr = ArrayVector::data_ptr(vec.as_ptr());
+            let drop_offset = if mem::size_of::<T>() == 0 {
+                0
+            } else {
+                iter.as_slice().as_ptr().offset_from(vec_ptr) as usize
+            };
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(vec_ptr.add(drop_offset), drop_len));
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, N> {}
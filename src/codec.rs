@@ -0,0 +1,212 @@
+use core::{fmt, mem, ptr};
+
+use super::allocator::Allocator;
+use super::growth_policy::GrowthPolicy;
+use super::vector::Vector;
+
+
+pub const MAX_VARINT_LEN: usize = 10;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    UnexpectedEnd {
+        needed: usize,
+        remaining: usize,
+    },
+    VarintOverflow,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::UnexpectedEnd { needed, remaining } => {
+                write!(f, "unexpected end of input: needed {} bytes but only {} remain", needed, remaining)
+            },
+            DecodeError::VarintOverflow => {
+                f.write_str("varint does not fit in 64 bits")
+            },
+        }
+    }
+}
+
+
+impl<A: Allocator, G: GrowthPolicy> Vector<u8, A, G> {
+    #[inline]
+    pub fn put_slice(&mut self, src: &[u8]) {
+        self.reserve(src.len());
+
+        unsafe {
+            let len = self.len();
+            ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr().add(len), src.len());
+            self.set_len(len + src.len());
+        }
+    }
+    #[inline(always)]
+    pub fn put_u8(&mut self, value: u8) {
+        self.push(value);
+    }
+    #[inline(always)]
+    pub fn put_i8(&mut self, value: i8) {
+        self.push(value as u8);
+    }
+    #[inline]
+    pub fn put_varint(&mut self, mut value: u64) {
+        let mut buf = [0u8; MAX_VARINT_LEN];
+        let mut n = 0;
+
+        while value >= 0x80 {
+            buf[n] = value as u8 | 0x80;
+            value >>= 7;
+            n += 1;
+        }
+        buf[n] = value as u8;
+
+        self.put_slice(&buf[..=n]);
+    }
+}
+
+macro_rules! impl_put {
+    ($($ty:ident => $put:ident, $put_le:ident, $put_ne:ident;)*) => {
+        impl<A: Allocator, G: GrowthPolicy> Vector<u8, A, G> {
+            $(
+                #[inline(always)]
+                pub fn $put(&mut self, value: $ty) {
+                    self.put_slice(&value.to_be_bytes());
+                }
+                #[inline(always)]
+                pub fn $put_le(&mut self, value: $ty) {
+                    self.put_slice(&value.to_le_bytes());
+                }
+                #[inline(always)]
+                pub fn $put_ne(&mut self, value: $ty) {
+                    self.put_slice(&value.to_ne_bytes());
+                }
+            )*
+        }
+    };
+}
+
+impl_put! {
+    u16 => put_u16, put_u16_le, put_u16_ne;
+    u32 => put_u32, put_u32_le, put_u32_ne;
+    u64 => put_u64, put_u64_le, put_u64_ne;
+    u128 => put_u128, put_u128_le, put_u128_ne;
+    i16 => put_i16, put_i16_le, put_i16_ne;
+    i32 => put_i32, put_i32_le, put_i32_ne;
+    i64 => put_i64, put_i64_le, put_i64_ne;
+    i128 => put_i128, put_i128_le, put_i128_ne;
+    f32 => put_f32, put_f32_le, put_f32_ne;
+    f64 => put_f64, put_f64_le, put_f64_ne;
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ByteReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    #[inline(always)]
+    pub const fn new(buf: &'a [u8]) -> Self {
+        ByteReader { buf }
+    }
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len()
+    }
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.buf
+    }
+    #[inline]
+    pub fn advance(&mut self, n: usize) -> Result<(), DecodeError> {
+        self.get_slice(n).map(|_| ())
+    }
+    #[inline]
+    pub fn get_slice(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if n > self.buf.len() {
+            return Err(DecodeError::UnexpectedEnd { needed: n, remaining: self.buf.len() });
+        }
+        let (head, tail) = self.buf.split_at(n);
+        self.buf = tail;
+        Ok(head)
+    }
+    #[inline]
+    pub fn get_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.get_slice(N)?);
+        Ok(array)
+    }
+    #[inline]
+    pub fn get_u8(&mut self) -> Result<u8, DecodeError> {
+        self.get_array::<1>().map(|[byte]| byte)
+    }
+    #[inline]
+    pub fn get_i8(&mut self) -> Result<i8, DecodeError> {
+        self.get_u8().map(|byte| byte as i8)
+    }
+    #[inline]
+    pub fn get_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+
+        for (i, &byte) in self.buf.iter().take(MAX_VARINT_LEN).enumerate() {
+            if i == MAX_VARINT_LEN - 1 && byte > 1 {
+                return Err(DecodeError::VarintOverflow);
+            }
+            value |= u64::from(byte & 0x7f) << (7 * i);
+
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[i + 1..];
+                return Ok(value);
+            }
+        }
+
+        Err(DecodeError::UnexpectedEnd { needed: self.buf.len() + 1, remaining: self.buf.len() })
+    }
+}
+
+impl<'a> From<&'a [u8]> for ByteReader<'a> {
+    #[inline(always)]
+    fn from(buf: &'a [u8]) -> Self {
+        ByteReader::new(buf)
+    }
+}
+
+macro_rules! impl_get {
+    ($($ty:ident => $get:ident, $get_le:ident, $get_ne:ident;)*) => {
+        impl<'a> ByteReader<'a> {
+            $(
+                #[inline]
+                pub fn $get(&mut self) -> Result<$ty, DecodeError> {
+                    self.get_array::<{ mem::size_of::<$ty>() }>().map($ty::from_be_bytes)
+                }
+                #[inline]
+                pub fn $get_le(&mut self) -> Result<$ty, DecodeError> {
+                    self.get_array::<{ mem::size_of::<$ty>() }>().map($ty::from_le_bytes)
+                }
+                #[inline]
+                pub fn $get_ne(&mut self) -> Result<$ty, DecodeError> {
+                    self.get_array::<{ mem::size_of::<$ty>() }>().map($ty::from_ne_bytes)
+                }
+            )*
+        }
+    };
+}
+
+impl_get! {
+    u16 => get_u16, get_u16_le, get_u16_ne;
+    u32 => get_u32, get_u32_le, get_u32_ne;
+    u64 => get_u64, get_u64_le, get_u64_ne;
+    u128 => get_u128, get_u128_le, get_u128_ne;
+    i16 => get_i16, get_i16_le, get_i16_ne;
+    i32 => get_i32, get_i32_le, get_i32_ne;
+    i64 => get_i64, get_i64_le, get_i64_ne;
+    i128 => get_i128, get_i128_le, get_i128_ne;
+    f32 => get_f32, get_f32_le, get_f32_ne;
+    f64 => get_f64, get_f64_le, get_f64_ne;
+}
@@ -0,0 +1,116 @@
+use core::cmp;
+
+use super::raw_buffer::min_non_zero_cap;
+
+
+pub trait GrowthPolicy {
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize>;
+
+    #[inline(always)]
+    fn shrink(&self, _len: usize, _cap: usize) -> Option<usize> {
+        None
+    }
+}
+
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Doubling;
+
+impl GrowthPolicy for Doubling {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        Some(cmp::max(min_non_zero_cap(elem_size), cmp::max(cap.saturating_mul(2), required)))
+    }
+}
+
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct OneAndAHalf;
+
+impl GrowthPolicy for OneAndAHalf {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        Some(cmp::max(min_non_zero_cap(elem_size), cmp::max(cap.saturating_add(cap / 2), required)))
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FixedIncrement(pub usize);
+
+impl Default for FixedIncrement {
+    #[inline(always)]
+    fn default() -> Self {
+        FixedIncrement(16)
+    }
+}
+
+impl GrowthPolicy for FixedIncrement {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, _elem_size: usize) -> Option<usize> {
+        let increment = cmp::max(self.0, 1);
+        let missing = required.saturating_sub(cap);
+        let steps = cmp::max(1, missing.div_ceil(increment));
+        steps.checked_mul(increment).and_then(|grow_by| cap.checked_add(grow_by))
+    }
+}
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PageAligned(pub usize);
+
+impl Default for PageAligned {
+    #[inline(always)]
+    fn default() -> Self {
+        PageAligned(4096)
+    }
+}
+
+impl GrowthPolicy for PageAligned {
+    #[inline]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        let target = cmp::max(cap.saturating_mul(2), required);
+
+        if elem_size == 0 {
+            return Some(target);
+        }
+
+        let page = cmp::max(self.0, 1);
+        let bytes = target.checked_mul(elem_size)?;
+        let bytes = bytes.checked_next_multiple_of(page)?;
+        Some(cmp::max(bytes / elem_size, required))
+    }
+}
+
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct Exact;
+
+impl GrowthPolicy for Exact {
+    #[inline(always)]
+    fn grow(&self, _cap: usize, required: usize, _elem_size: usize) -> Option<usize> {
+        Some(required)
+    }
+}
+
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct ShrinkOnPop<G = Doubling>(pub G);
+
+impl<G: GrowthPolicy> GrowthPolicy for ShrinkOnPop<G> {
+    #[inline(always)]
+    fn grow(&self, cap: usize, required: usize, elem_size: usize) -> Option<usize> {
+        self.0.grow(cap, required, elem_size)
+    }
+    #[inline]
+    fn shrink(&self, len: usize, cap: usize) -> Option<usize> {
+        if cap > MIN_SHRINK_CAP && len <= cap / 4 {
+            Some(cmp::max(cap / 2, MIN_SHRINK_CAP))
+        } else {
+            None
+        }
+    }
+}
+
+
+const MIN_SHRINK_CAP: usize = 8;
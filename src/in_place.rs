@@ -0,0 +1,114 @@
+use core::{mem, ptr};
+use core::iter::{InPlaceIterable, SourceIter};
+use core::num::NonZeroUsize;
+
+use super::allocator::{Allocator, Global};
+use super::vector::{IntoIter, SpecExtend, SpecFromIter, Vector};
+
+
+pub(crate) trait AsIntoIter {
+    type Item;
+
+    fn as_into_iter(&mut self) -> &mut IntoIter<Self::Item>;
+}
+
+impl<T> AsIntoIter for IntoIter<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn as_into_iter(&mut self) -> &mut IntoIter<T> {
+        self
+    }
+}
+
+unsafe impl<T, A: Allocator> SourceIter for IntoIter<T, A> {
+    type Source = Self;
+
+    #[inline(always)]
+    unsafe fn as_inner(&mut self) -> &mut Self {
+        self
+    }
+}
+
+unsafe impl<T, A: Allocator> InPlaceIterable for IntoIter<T, A> {
+    const EXPAND_BY: Option<NonZeroUsize> = NonZeroUsize::new(1);
+    const MERGE_BY: Option<NonZeroUsize> = NonZeroUsize::new(1);
+}
+
+
+impl<T, I: Iterator<Item = T>> SpecFromIter<T, I> for Vector<T> {
+    #[inline(always)]
+    default fn from_iter(iter: I) -> Self {
+        <Self as SpecExtend<_, _, _>>::from_iter_in(iter, Global)
+    }
+}
+
+impl<T, I> SpecFromIter<T, I> for Vector<T>
+    where I: Iterator<Item = T> + SourceIter + InPlaceIterable,
+          I::Source: AsIntoIter,
+{
+    #[inline]
+    fn from_iter(iter: I) -> Self {
+        if in_place_collectible::<<I::Source as AsIntoIter>::Item, T>(I::EXPAND_BY, I::MERGE_BY) {
+            unsafe { from_iter_in_place(iter) }
+        } else {
+            <Self as SpecExtend<_, _, _>>::from_iter_in(iter, Global)
+        }
+    }
+}
+
+
+#[inline(always)]
+fn in_place_collectible<S, T>(expand_by: Option<NonZeroUsize>, merge_by: Option<NonZeroUsize>) -> bool {
+    if mem::size_of::<S>() == 0 || mem::size_of::<S>() != mem::size_of::<T>() ||
+        mem::align_of::<S>() != mem::align_of::<T>() {
+        return false;
+    }
+    match (expand_by, merge_by) {
+        (Some(expand_by), Some(merge_by)) => merge_by >= expand_by,
+        _ => false,
+    }
+}
+
+unsafe fn from_iter_in_place<T, I>(mut iterator: I) -> Vector<T>
+    where I: Iterator<Item = T> + SourceIter,
+          I::Source: AsIntoIter,
+{
+    let (src_buf, cap) = iterator.as_inner().as_into_iter().buffer();
+    let dst_buf = src_buf as *mut T;
+
+    let mut sink = InPlaceDrop { inner: dst_buf, dst: dst_buf };
+
+    while let Some(element) = iterator.next() {
+        debug_assert!(sink.dst.addr() <= iterator.as_inner().as_into_iter().as_slice().as_ptr().addr());
+        ptr::write(sink.dst, element);
+        sink.dst = sink.dst.add(1);
+    }
+
+    let len = sink.dst.offset_from(dst_buf) as usize;
+    mem::forget(sink);
+
+    let mut vector = Vector::from_raw_parts(dst_buf, len, cap);
+    iterator.as_inner().as_into_iter().forget_allocation_drop_remaining();
+
+    if len <= cap / 4 {
+        vector.shrink_to_fit();
+    }
+    vector
+}
+
+
+struct InPlaceDrop<T> {
+    inner: *mut T,
+    dst: *mut T,
+}
+
+impl<T> Drop for InPlaceDrop<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let len = self.dst.offset_from(self.inner) as usize;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.inner, len));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use core::cmp;
+
+use std::io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+
+use super::allocator::{Allocator, Global};
+use super::growth_policy::{Doubling, GrowthPolicy};
+use super::vector::Vector;
+
+
+impl<A: Allocator, G: GrowthPolicy> Write for Vector<u8, A, G> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        let len = bufs.iter().map(|buf| buf.len()).sum();
+        self.reserve(len);
+
+        for buf in bufs {
+            self.extend_from_slice(buf);
+        }
+        Ok(len)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+    #[inline(always)]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VectorCursor<A: Allocator = Global, G: GrowthPolicy = Doubling> {
+    inner: Vector<u8, A, G>,
+    pos: u64,
+}
+
+impl<A: Allocator, G: GrowthPolicy> VectorCursor<A, G> {
+    #[inline(always)]
+    pub fn new(inner: Vector<u8, A, G>) -> Self {
+        VectorCursor { inner, pos: 0 }
+    }
+    #[inline(always)]
+    pub fn into_inner(self) -> Vector<u8, A, G> {
+        self.inner
+    }
+    #[inline(always)]
+    pub fn get_ref(&self) -> &Vector<u8, A, G> {
+        &self.inner
+    }
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> &mut Vector<u8, A, G> {
+        &mut self.inner
+    }
+    #[inline(always)]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+    #[inline(always)]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+    #[inline]
+    pub fn remaining_slice(&self) -> &[u8] {
+        let start = cmp::min(self.pos, self.inner.len() as u64) as usize;
+        &self.inner[start..]
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.remaining_slice().is_empty()
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> From<Vector<u8, A, G>> for VectorCursor<A, G> {
+    #[inline(always)]
+    fn from(inner: Vector<u8, A, G>) -> Self {
+        VectorCursor::new(inner)
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> Read for VectorCursor<A, G> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = Read::read(&mut self.remaining_slice(), buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        let n = Read::read_vectored(&mut self.remaining_slice(), bufs)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        Read::read_exact(&mut self.remaining_slice(), buf)?;
+        self.pos += buf.len() as u64;
+        Ok(())
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> BufRead for VectorCursor<A, G> {
+    #[inline(always)]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+    #[inline(always)]
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> Seek for VectorCursor<A, G> {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.inner.len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+    #[inline(always)]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
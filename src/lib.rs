@@ -1,44 +1,82 @@
-#![feature(alloc)]
-#![feature(inclusive_range)]
-#![feature(specialization)]
-#![feature(shared)]
-#![feature(trusted_len)]
-#![feature(fused)]
-#![feature(core_intrinsics)]
-#![feature(exact_size_is_empty)]
-#![feature(collections_range)]
-#![feature(box_syntax)]
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(trusted_len, specialization, inplace_iteration))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 
 
 pub extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 extern crate collection_traits;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 
+pub mod allocator;
+pub mod array_vector;
+pub mod codec;
+pub mod growth_policy;
+#[cfg(feature = "nightly")]
+mod in_place;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod persistent_vector;
+pub mod raw_buffer;
+#[cfg(feature = "serde")]
+pub mod serde_bytes;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod shared_vector;
+pub mod small_vector;
+pub mod sorted_vector;
+pub mod sorted_vector_map;
 pub mod vector;
+pub mod vector_deque;
+pub mod vector_string;
 
 
+pub use self::allocator::{Allocator, AllocError, Global};
+pub use self::array_vector::{ArrayVector, CapacityError};
+pub use self::codec::{ByteReader, DecodeError};
+pub use self::growth_policy::{GrowthPolicy, Doubling, OneAndAHalf, FixedIncrement, PageAligned, Exact, ShrinkOnPop};
+#[cfg(feature = "std")]
+pub use self::io::VectorCursor;
+pub use self::persistent_vector::PersistentVector;
+pub use self::raw_buffer::{RawBuffer, TryReserveError};
+pub use self::shared_vector::{ArcVector, RcVector, RefCount, SharedVector};
+pub use self::small_vector::SmallVector;
+pub use self::sorted_vector::SortedVector;
+pub use self::sorted_vector_map::SortedVectorMap;
 pub use self::vector::Vector;
+pub use self::vector_deque::VectorDeque;
+pub use self::vector_string::{FromUtf8Error, VectorString};
 
 
 #[macro_export]
 macro_rules! vector {
+    (in $alloc:expr; $elem:expr; $n:expr) => (
+        $crate::vector::from_elem_in($elem, $n, $alloc)
+    );
+    (in $alloc:expr; $($x:expr),* $(,)*) => ({
+        let mut vector = $crate::Vector::new_in($alloc);
+        vector.extend([$($x),*]);
+        vector
+    });
     ($elem:expr; $n:expr) => (
         $crate::vector::from_elem($elem, $n)
     );
     ($($x:expr),*) => (
-        $crate::slice_to_vector($crate::alloc::boxed::Box::new([$($x),*]))
+        <$crate::Vector<_>>::from([$($x),*])
     );
     ($($x:expr,)*) => (vector![$($x),*])
 }
 
-
-#[inline]
-pub fn slice_to_vector<T>(mut slice: alloc::boxed::Box<[T]>) -> Vector<T> {
-    unsafe {
-        let vector = Vector::from_raw_parts(slice.as_mut_ptr(), slice.len(), slice.len());
-        core::mem::forget(slice);
-        vector
-    }
+#[macro_export]
+macro_rules! array_vector {
+    ($elem:expr; $n:expr) => ($crate::ArrayVector::from_array([$elem; $n]));
+    ($($x:expr),* $(,)*) => ($crate::ArrayVector::from_array([$($x),*]));
 }
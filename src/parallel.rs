@@ -0,0 +1,400 @@
+use alloc::collections::LinkedList;
+
+use core::{fmt, mem, ptr, slice};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Range, RangeBounds};
+
+use rayon::iter::plumbing::{bridge, Consumer, Folder, Producer, ProducerCallback, Reducer, UnindexedConsumer};
+use rayon::iter::{FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelDrainRange,
+                  ParallelExtend, ParallelIterator};
+
+use super::allocator::{Allocator, Global};
+use super::growth_policy::{Doubling, GrowthPolicy};
+use super::vector::{range_bounds, Vector};
+
+
+impl<'a, T: Sync + 'a, A: Allocator, G: GrowthPolicy> IntoParallelIterator for &'a Vector<T, A, G> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, T: Send + 'a, A: Allocator, G: GrowthPolicy> IntoParallelIterator for &'a mut Vector<T, A, G> {
+    type Item = &'a mut T;
+    type Iter = rayon::slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice_mut().into_par_iter()
+    }
+}
+
+
+pub struct IntoIter<T: Send, A: Allocator + Send = Global, G: GrowthPolicy + Send = Doubling> {
+    vector: Vector<T, A, G>,
+}
+
+impl<T: Send + fmt::Debug, A: Allocator + Send, G: GrowthPolicy + Send> fmt::Debug for IntoIter<T, A, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.vector.as_slice())
+            .finish()
+    }
+}
+
+impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> IntoParallelIterator for Vector<T, A, G> {
+    type Item = T;
+    type Iter = IntoIter<T, A, G>;
+
+    #[inline(always)]
+    fn into_par_iter(self) -> Self::Iter {
+        IntoIter { vector: self }
+    }
+}
+
+impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> ParallelIterator for IntoIter<T, A, G> {
+    type Item = T;
+
+    #[inline]
+    fn drive_unindexed<C: UnindexedConsumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+    #[inline(always)]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.vector.len())
+    }
+}
+
+impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> IndexedParallelIterator for IntoIter<T, A, G> {
+    #[inline]
+    fn drive<C: Consumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.vector.len()
+    }
+    #[inline]
+    fn with_producer<CB: ProducerCallback<T>>(mut self, callback: CB) -> CB::Output {
+        self.vector.par_drain(..).with_producer(callback)
+    }
+}
+
+
+impl<'a, T: Send, A: Allocator + Send, G: GrowthPolicy + Send> ParallelDrainRange<usize> for &'a mut Vector<T, A, G> {
+    type Iter = Drain<'a, T, A, G>;
+    type Item = T;
+
+    #[inline]
+    fn par_drain<R: RangeBounds<usize>>(self, range: R) -> Self::Iter {
+        let (start, end) = range_bounds(&range, self.len());
+
+        Drain {
+            orig_len: self.len(),
+            range: start..end,
+            vector: self,
+        }
+    }
+}
+
+pub struct Drain<'a, T: 'a + Send, A: 'a + Allocator + Send = Global, G: 'a + GrowthPolicy + Send = Doubling> {
+    vector: &'a mut Vector<T, A, G>,
+    range: Range<usize>,
+    orig_len: usize,
+}
+
+impl<'a, T: Send + fmt::Debug, A: Allocator + Send, G: GrowthPolicy + Send> fmt::Debug for Drain<'a, T, A, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Drain")
+            .field(&&self.vector[self.range.clone()])
+            .finish()
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Send, G: GrowthPolicy + Send> ParallelIterator for Drain<'a, T, A, G> {
+    type Item = T;
+
+    #[inline]
+    fn drive_unindexed<C: UnindexedConsumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+    #[inline(always)]
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.range.len())
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Send, G: GrowthPolicy + Send> IndexedParallelIterator for Drain<'a, T, A, G> {
+    #[inline]
+    fn drive<C: Consumer<T>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+    #[inline]
+    fn with_producer<CB: ProducerCallback<T>>(self, callback: CB) -> CB::Output {
+        unsafe {
+            self.vector.set_len(self.range.start);
+
+            let ptr = self.vector.as_mut_ptr().add(self.range.start);
+            let producer = DrainProducer {
+                slice: slice::from_raw_parts_mut(ptr, self.range.len()),
+            };
+            callback.callback(producer)
+        }
+    }
+}
+
+impl<'a, T: Send, A: Allocator + Send, G: GrowthPolicy + Send> Drop for Drain<'a, T, A, G> {
+    fn drop(&mut self) {
+        let Range { start, end } = self.range;
+
+        if self.vector.len() == self.orig_len {
+            self.vector.drain(start..end);
+        } else if start == end {
+            unsafe {
+                self.vector.set_len(self.orig_len);
+            }
+        } else if end < self.orig_len {
+            unsafe {
+                let ptr = self.vector.as_mut_ptr();
+                let tail_len = self.orig_len - end;
+                ptr::copy(ptr.add(end), ptr.add(start), tail_len);
+                self.vector.set_len(start + tail_len);
+            }
+        }
+    }
+}
+
+
+struct DrainProducer<'a, T: 'a + Send> {
+    slice: &'a mut [T],
+}
+
+impl<'a, T: 'a + Send> Producer for DrainProducer<'a, T> {
+    type Item = T;
+    type IntoIter = SliceDrain<'a, T>;
+
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter {
+        SliceDrain {
+            iter: mem::take(&mut self.slice).iter_mut(),
+        }
+    }
+    #[inline]
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let (left, right) = mem::take(&mut self.slice).split_at_mut(index);
+        (DrainProducer { slice: left }, DrainProducer { slice: right })
+    }
+}
+
+impl<'a, T: 'a + Send> Drop for DrainProducer<'a, T> {
+    fn drop(&mut self) {
+        let slice: *mut [T] = mem::take::<&mut [T]>(&mut self.slice);
+        unsafe {
+            ptr::drop_in_place(slice);
+        }
+    }
+}
+
+
+struct SliceDrain<'a, T: 'a> {
+    iter: slice::IterMut<'a, T>,
+}
+
+impl<'a, T: 'a> Iterator for SliceDrain<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|element| unsafe { ptr::read(element) })
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for SliceDrain<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|element| unsafe { ptr::read(element) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for SliceDrain<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for SliceDrain<'a, T> {}
+
+impl<'a, T: 'a> Drop for SliceDrain<'a, T> {
+    fn drop(&mut self) {
+        let iter = mem::replace(&mut self.iter, [].iter_mut());
+        unsafe {
+            ptr::drop_in_place(iter.into_slice());
+        }
+    }
+}
+
+
+impl<T: Send, A: Allocator, G: GrowthPolicy> ParallelExtend<T> for Vector<T, A, G> {
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I) {
+        let par_iter = par_iter.into_par_iter();
+
+        match par_iter.opt_len() {
+            Some(len) => {
+                self.reserve(len);
+
+                let result = par_iter.drive_unindexed(CollectConsumer {
+                    target: &mut self.spare_capacity_mut()[..len],
+                });
+
+                let written = result.initialized_len;
+                assert!(written == len, "expected {} total writes, but got {}", len, written);
+                mem::forget(result);
+
+                let new_len = self.len() + len;
+                unsafe {
+                    self.set_len(new_len);
+                }
+            }
+            None => {
+                let list = par_iter
+                    .fold(Vector::new, |mut chunk, element| {
+                        chunk.push(element);
+                        chunk
+                    })
+                    .map(|chunk| {
+                        let mut list = LinkedList::new();
+                        list.push_back(chunk);
+                        list
+                    })
+                    .reduce(LinkedList::new, |mut left, mut right| {
+                        left.append(&mut right);
+                        left
+                    });
+
+                self.reserve(list.iter().map(Vector::len).sum());
+                for chunk in list {
+                    self.extend(chunk);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy + Send + Sync, A: Allocator, G: GrowthPolicy> ParallelExtend<&'a T> for Vector<T, A, G> {
+    #[inline]
+    fn par_extend<I: IntoParallelIterator<Item = &'a T>>(&mut self, par_iter: I) {
+        self.par_extend(par_iter.into_par_iter().copied())
+    }
+}
+
+impl<T: Send, A: Allocator + Default, G: GrowthPolicy + Default> FromParallelIterator<T> for Vector<T, A, G> {
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        let mut vector = Vector::default();
+        vector.par_extend(par_iter);
+        vector
+    }
+}
+
+
+struct CollectConsumer<'c, T: 'c + Send> {
+    target: &'c mut [MaybeUninit<T>],
+}
+
+struct CollectResult<'c, T: 'c> {
+    start: *mut T,
+    total_len: usize,
+    initialized_len: usize,
+    marker: PhantomData<&'c mut [T]>,
+}
+
+unsafe impl<'c, T: Send> Send for CollectResult<'c, T> {}
+
+impl<'c, T> Drop for CollectResult<'c, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.start, self.initialized_len));
+        }
+    }
+}
+
+impl<'c, T: Send + 'c> Consumer<T> for CollectConsumer<'c, T> {
+    type Folder = CollectResult<'c, T>;
+    type Reducer = CollectReducer;
+    type Result = CollectResult<'c, T>;
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self, CollectReducer) {
+        let (left, right) = self.target.split_at_mut(index);
+        (CollectConsumer { target: left }, CollectConsumer { target: right }, CollectReducer)
+    }
+    #[inline]
+    fn into_folder(self) -> Self::Folder {
+        CollectResult {
+            start: self.target.as_mut_ptr() as *mut T,
+            total_len: self.target.len(),
+            initialized_len: 0,
+            marker: PhantomData,
+        }
+    }
+    #[inline(always)]
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'c, T: Send + 'c> UnindexedConsumer<T> for CollectConsumer<'c, T> {
+    fn split_off_left(&self) -> Self {
+        unreachable!("CollectConsumer must be indexed")
+    }
+    #[inline(always)]
+    fn to_reducer(&self) -> Self::Reducer {
+        CollectReducer
+    }
+}
+
+impl<'c, T: Send + 'c> Folder<T> for CollectResult<'c, T> {
+    type Result = Self;
+
+    #[inline]
+    fn consume(mut self, element: T) -> Self {
+        assert!(self.initialized_len < self.total_len, "too many values pushed to consumer");
+
+        unsafe {
+            ptr::write(self.start.add(self.initialized_len), element);
+        }
+        self.initialized_len += 1;
+        self
+    }
+    #[inline(always)]
+    fn complete(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+struct CollectReducer;
+
+impl<'c, T> Reducer<CollectResult<'c, T>> for CollectReducer {
+    #[inline]
+    fn reduce(self, mut left: CollectResult<'c, T>, mut right: CollectResult<'c, T>) -> CollectResult<'c, T> {
+        if left.start.wrapping_add(left.initialized_len) == right.start {
+            left.total_len += right.total_len;
+            left.initialized_len += mem::replace(&mut right.initialized_len, 0);
+        }
+        left
+    }
+}
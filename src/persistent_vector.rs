@@ -0,0 +1,630 @@
+use alloc::sync::Arc;
+
+use core::{cmp, fmt, mem, slice};
+use core::hash::{self, Hash};
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::Index;
+
+use collection_traits::*;
+
+use super::vector::{self, Vector};
+
+
+const BITS: usize = 5;
+const BRANCH: usize = 1 << BITS;
+const EXTRA: usize = 2;
+
+
+pub struct PersistentVector<T> {
+    root: Arc<Node<T>>,
+    shift: usize,
+    len: usize,
+}
+
+#[derive(Clone)]
+enum Node<T> {
+    Leaf(Vector<T>),
+    Branch(Vector<Arc<Node<T>>>, Vector<usize>),
+}
+
+impl<T> PersistentVector<T> {
+    #[inline]
+    pub fn new() -> Self {
+        PersistentVector {
+            root: Arc::new(Node::Leaf(Vector::new())),
+            shift: 0,
+            len: 0,
+        }
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = PersistentVector::new();
+    }
+    #[inline(always)]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.root, &other.root)
+    }
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            let (items, index) = self.leaf(index);
+            Some(&items[index])
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            vector: self,
+            front: 0,
+            back: self.len,
+            front_leaf: [].iter(),
+            back_leaf: [].iter(),
+        }
+    }
+
+    #[inline]
+    fn leaf(&self, mut index: usize) -> (&[T], usize) {
+        let mut node = &*self.root;
+        let mut shift = self.shift;
+
+        loop {
+            match *node {
+                Node::Leaf(ref items) => return (items, index),
+                Node::Branch(ref children, ref sizes) => {
+                    let i = child_index(sizes, shift, index);
+                    if i != 0 {
+                        index -= sizes[i - 1];
+                    }
+                    node = &children[i];
+                    shift -= BITS;
+                },
+            }
+        }
+    }
+    #[inline]
+    fn from_root(mut root: Arc<Node<T>>, mut shift: usize, len: usize) -> Self {
+        loop {
+            let child = match *root {
+                Node::Branch(ref children, _) if children.len() == 1 => children[0].clone(),
+                _ => break,
+            };
+            root = child;
+            shift -= BITS;
+        }
+        PersistentVector { root, shift, len }
+    }
+    #[inline]
+    fn from_nodes(mut nodes: Vector<Arc<Node<T>>>, mut shift: usize, len: usize) -> Self {
+        while nodes.len() > 1 {
+            nodes = branches(&nodes);
+            shift += BITS;
+        }
+        match nodes.pop() {
+            Some(root) => PersistentVector::from_root(root, shift, len),
+            None => PersistentVector::new(),
+        }
+    }
+}
+
+impl<T: Clone> PersistentVector<T> {
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            Some(get_mut(&mut self.root, self.shift, index))
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) -> T {
+        mem::replace(self.get_mut(index).expect("index out of bounds"), value)
+    }
+    #[inline]
+    pub fn update(&self, index: usize, value: T) -> Self {
+        let mut vector = self.clone();
+        vector.set(index, value);
+        vector
+    }
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        if let Some(value) = push(&mut self.root, self.shift, value) {
+            let sibling = Arc::new(Node::path(self.shift, value));
+            let root = Node::branch(Vector::from([self.root.clone(), sibling]));
+            self.root = Arc::new(root);
+            self.shift += BITS;
+        }
+        self.len += 1;
+    }
+    #[inline]
+    pub fn split_at(&self, index: usize) -> (Self, Self) {
+        assert!(index <= self.len, "index out of bounds");
+
+        let left = if index == 0 {
+            PersistentVector::new()
+        } else {
+            PersistentVector::from_root(take(&self.root, self.shift, index), self.shift, index)
+        };
+        let right = if index == self.len {
+            PersistentVector::new()
+        } else {
+            PersistentVector::from_root(skip(&self.root, self.shift, index), self.shift, self.len - index)
+        };
+        (left, right)
+    }
+    #[inline]
+    pub fn concat(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            other.clone()
+        } else if other.is_empty() {
+            self.clone()
+        } else {
+            let nodes = merge(&self.root, self.shift, &other.root, other.shift);
+            PersistentVector::from_nodes(nodes, cmp::max(self.shift, other.shift), self.len + other.len)
+        }
+    }
+}
+
+
+impl<T> Node<T> {
+    #[inline]
+    fn branch(children: Vector<Arc<Node<T>>>) -> Self {
+        let mut total = 0;
+        let sizes = children.iter()
+            .map(|child| {
+                total += child.len();
+                total
+            })
+            .collect();
+        Node::Branch(children, sizes)
+    }
+    #[inline]
+    fn path(shift: usize, value: T) -> Self {
+        if shift == 0 {
+            Node::Leaf(Vector::from([value]))
+        } else {
+            Node::branch(Vector::from([Arc::new(Node::path(shift - BITS, value))]))
+        }
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        match *self {
+            Node::Leaf(ref items) => items.len(),
+            Node::Branch(_, ref sizes) => sizes.last().cloned().unwrap_or(0),
+        }
+    }
+    #[inline]
+    fn slots(&self) -> usize {
+        match *self {
+            Node::Leaf(ref items) => items.len(),
+            Node::Branch(ref children, _) => children.len(),
+        }
+    }
+    #[inline]
+    fn items(&self) -> &[T] {
+        match *self {
+            Node::Leaf(ref items) => items,
+            Node::Branch(..) => unreachable!(),
+        }
+    }
+    #[inline]
+    fn children(&self) -> &[Arc<Node<T>>] {
+        match *self {
+            Node::Leaf(_) => unreachable!(),
+            Node::Branch(ref children, _) => children,
+        }
+    }
+}
+
+
+#[inline]
+fn child_index(sizes: &[usize], shift: usize, index: usize) -> usize {
+    let mut i = cmp::min(index >> shift, sizes.len() - 1);
+    while sizes[i] <= index {
+        i += 1;
+    }
+    i
+}
+
+#[inline]
+fn branches<T>(nodes: &[Arc<Node<T>>]) -> Vector<Arc<Node<T>>> {
+    nodes.chunks(BRANCH)
+        .map(|chunk| Arc::new(Node::branch(Vector::from(chunk))))
+        .collect()
+}
+
+fn get_mut<T: Clone>(node: &mut Arc<Node<T>>, shift: usize, mut index: usize) -> &mut T {
+    match *Arc::make_mut(node) {
+        Node::Leaf(ref mut items) => &mut items[index],
+        Node::Branch(ref mut children, ref sizes) => {
+            let i = child_index(sizes, shift, index);
+            if i != 0 {
+                index -= sizes[i - 1];
+            }
+            get_mut(&mut children[i], shift - BITS, index)
+        },
+    }
+}
+
+fn push<T: Clone>(node: &mut Arc<Node<T>>, shift: usize, value: T) -> Option<T> {
+    if let Node::Leaf(ref items) = **node {
+        if items.len() == BRANCH {
+            return Some(value);
+        }
+    }
+
+    match *Arc::make_mut(node) {
+        Node::Leaf(ref mut items) => {
+            items.push(value);
+            None
+        },
+        Node::Branch(ref mut children, ref mut sizes) => {
+            let total = sizes[sizes.len() - 1];
+            let last = children.len() - 1;
+
+            match push(&mut children[last], shift - BITS, value) {
+                None => {
+                    sizes[last] += 1;
+                    None
+                },
+                Some(value) if children.len() < BRANCH => {
+                    children.push(Arc::new(Node::path(shift - BITS, value)));
+                    sizes.push(total + 1);
+                    None
+                },
+                Some(value) => Some(value),
+            }
+        },
+    }
+}
+
+fn take<T: Clone>(node: &Arc<Node<T>>, shift: usize, n: usize) -> Arc<Node<T>> {
+    if n == node.len() {
+        return node.clone();
+    }
+    match **node {
+        Node::Leaf(ref items) => Arc::new(Node::Leaf(Vector::from(&items[..n]))),
+        Node::Branch(ref children, ref sizes) => {
+            let i = child_index(sizes, shift, n - 1);
+            let before = if i == 0 { 0 } else { sizes[i - 1] };
+
+            let mut taken = Vector::with_capacity(i + 1);
+            taken.extend_from_slice(&children[..i]);
+            taken.push(take(&children[i], shift - BITS, n - before));
+            Arc::new(Node::branch(taken))
+        },
+    }
+}
+
+fn skip<T: Clone>(node: &Arc<Node<T>>, shift: usize, n: usize) -> Arc<Node<T>> {
+    if n == 0 {
+        return node.clone();
+    }
+    match **node {
+        Node::Leaf(ref items) => Arc::new(Node::Leaf(Vector::from(&items[n..]))),
+        Node::Branch(ref children, ref sizes) => {
+            let i = child_index(sizes, shift, n);
+            let before = if i == 0 { 0 } else { sizes[i - 1] };
+
+            let mut kept = Vector::with_capacity(children.len() - i);
+            kept.push(skip(&children[i], shift - BITS, n - before));
+            kept.extend_from_slice(&children[i + 1..]);
+            Arc::new(Node::branch(kept))
+        },
+    }
+}
+
+fn merge<T: Clone>(
+    left: &Arc<Node<T>>,
+    left_shift: usize,
+    right: &Arc<Node<T>>,
+    right_shift: usize,
+) -> Vector<Arc<Node<T>>> {
+    if left_shift > right_shift {
+        let (last, init) = left.children().split_last().unwrap();
+        let middle = merge(last, left_shift - BITS, right, right_shift);
+        rebalance(init, middle, &[])
+    } else if left_shift < right_shift {
+        let (first, rest) = right.children().split_first().unwrap();
+        let middle = merge(left, left_shift, first, right_shift - BITS);
+        rebalance(&[], middle, rest)
+    } else if left_shift == 0 {
+        let (l, r) = (left.items(), right.items());
+
+        if l.len() + r.len() <= BRANCH {
+            let mut items = Vector::with_capacity(l.len() + r.len());
+            items.extend_from_slice(l);
+            items.extend_from_slice(r);
+            Vector::from([Arc::new(Node::Leaf(items))])
+        } else {
+            Vector::from([left.clone(), right.clone()])
+        }
+    } else {
+        let (last, init) = left.children().split_last().unwrap();
+        let (first, rest) = right.children().split_first().unwrap();
+        let middle = merge(last, left_shift - BITS, first, right_shift - BITS);
+        rebalance(init, middle, rest)
+    }
+}
+
+fn rebalance<T: Clone>(
+    left: &[Arc<Node<T>>],
+    middle: Vector<Arc<Node<T>>>,
+    right: &[Arc<Node<T>>],
+) -> Vector<Arc<Node<T>>> {
+    let mut nodes = Vector::with_capacity(left.len() + middle.len() + right.len());
+    nodes.extend_from_slice(left);
+    nodes.extend(middle);
+    nodes.extend_from_slice(right);
+
+    let slots: usize = nodes.iter().map(|node| node.slots()).sum();
+    if nodes.len() > slots.div_ceil(BRANCH) + EXTRA {
+        nodes = match *nodes[0] {
+            Node::Leaf(_) => pack(nodes.iter().flat_map(|node| node.items()), Node::Leaf),
+            Node::Branch(..) => pack(nodes.iter().flat_map(|node| node.children()), Node::branch),
+        };
+    }
+    branches(&nodes)
+}
+
+fn pack<'a, S, T, I>(slots: I, make: fn(Vector<S>) -> Node<T>) -> Vector<Arc<Node<T>>>
+    where S: 'a + Clone,
+          I: Iterator<Item = &'a S>,
+{
+    let mut nodes = Vector::new();
+    let mut current = Vector::with_capacity(BRANCH);
+
+    for slot in slots {
+        if current.len() == BRANCH {
+            let full = mem::replace(&mut current, Vector::with_capacity(BRANCH));
+            nodes.push(Arc::new(make(full)));
+        }
+        current.push(slot.clone());
+    }
+    if !current.is_empty() {
+        nodes.push(Arc::new(make(current)));
+    }
+    nodes
+}
+
+fn drain_into<T: Clone>(node: Arc<Node<T>>, out: &mut Vector<T>) {
+    match Arc::try_unwrap(node) {
+        Ok(Node::Leaf(mut items)) => out.append(&mut items),
+        Ok(Node::Branch(children, _)) => for child in children {
+            drain_into(child, out);
+        },
+        Err(node) => match *node {
+            Node::Leaf(ref items) => out.extend_from_slice(items),
+            Node::Branch(ref children, _) => for child in children.iter() {
+                drain_into(child.clone(), out);
+            },
+        },
+    }
+}
+
+
+impl<T> Clone for PersistentVector<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        PersistentVector {
+            root: self.root.clone(),
+            shift: self.shift,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentVector<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        PersistentVector::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentVector<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentVector<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for PersistentVector<T> {}
+
+impl<T: Hash> Hash for PersistentVector<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for element in self {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T> Index<usize> for PersistentVector<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T> From<Vector<T>> for PersistentVector<T> {
+    #[inline]
+    fn from(vector: Vector<T>) -> Self {
+        let len = vector.len();
+        let mut iter = vector.into_iter();
+        let mut leaves = Vector::with_capacity(len.div_ceil(BRANCH));
+
+        while iter.len() != 0 {
+            leaves.push(Arc::new(Node::Leaf(iter.by_ref().take(BRANCH).collect())));
+        }
+        PersistentVector::from_nodes(leaves, 0, len)
+    }
+}
+
+impl<T: Clone> From<PersistentVector<T>> for Vector<T> {
+    #[inline]
+    fn from(vector: PersistentVector<T>) -> Self {
+        let mut out = Vector::with_capacity(vector.len);
+        drain_into(vector.root, &mut out);
+        out
+    }
+}
+
+impl<T> FromIterator<T> for PersistentVector<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        PersistentVector::from(iter.into_iter().collect::<Vector<T>>())
+    }
+}
+
+impl<T: Clone> Extend<T> for PersistentVector<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let other: PersistentVector<T> = iter.into_iter().collect();
+        *self = self.concat(&other);
+    }
+}
+
+impl<T: Clone> IntoIterator for PersistentVector<T> {
+    type Item = T;
+    type IntoIter = vector::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Vector::from(self).into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentVector<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Collection for PersistentVector<T> {
+    #[inline(always)]
+    fn len(&self) -> usize { self.len }
+}
+
+impl<T> CollectionMut for PersistentVector<T> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        PersistentVector::clear(self)
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, &'a T> for PersistentVector<T> {
+    type Iter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        PersistentVector::iter(self)
+    }
+}
+
+
+pub struct Iter<'a, T: 'a> {
+    vector: &'a PersistentVector<T>,
+    front: usize,
+    back: usize,
+    front_leaf: slice::Iter<'a, T>,
+    back_leaf: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter {
+            vector: self.vector,
+            front: self.front,
+            back: self.back,
+            front_leaf: self.front_leaf.clone(),
+            back_leaf: self.back_leaf.clone(),
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let element = match self.front_leaf.next() {
+            Some(element) => element,
+            None => {
+                let (items, index) = self.vector.leaf(self.front);
+                self.front_leaf = items[index + 1..].iter();
+                &items[index]
+            },
+        };
+        self.front += 1;
+        Some(element)
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let element = match self.back_leaf.next_back() {
+            Some(element) => element,
+            None => {
+                let (items, index) = self.vector.leaf(self.back - 1);
+                self.back_leaf = items[..index].iter();
+                &items[index]
+            },
+        };
+        self.back -= 1;
+        Some(element)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+impl<'a, T> FusedIterator for Iter<'a, T> {}
@@ -0,0 +1,269 @@
+use alloc::alloc::{handle_alloc_error, Layout};
+use alloc::boxed::Box;
+
+use core::{cmp, fmt, mem, ptr, slice};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use super::allocator::{Allocator, Global};
+
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TryReserveError {
+    CapacityOverflow,
+    AllocError {
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryReserveError::CapacityOverflow => {
+                f.write_str("memory allocation failed because the computed capacity exceeded the collection's maximum")
+            },
+            TryReserveError::AllocError { .. } => {
+                f.write_str("memory allocation failed because the memory allocator returned an error")
+            },
+        }
+    }
+}
+
+
+pub struct RawBuffer<T, A: Allocator = Global> {
+    ptr: NonNull<T>,
+    cap: usize,
+    alloc: A,
+    marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for RawBuffer<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for RawBuffer<T, A> {}
+
+impl<T> RawBuffer<T, Global> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+    #[inline(always)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_in(cap, Global)
+    }
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and either dangling with `cap == 0`, or
+    /// allocated by the global allocator for exactly `cap` elements of `T`.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts(ptr: *mut T, cap: usize) -> Self {
+        Self::from_raw_parts_in(ptr, cap, Global)
+    }
+    /// # Safety
+    ///
+    /// The first `len` elements must be initialized, and `len` must equal
+    /// `cap()` unless `T` is zero-sized.
+    #[inline]
+    pub unsafe fn into_box(self, len: usize) -> Box<[T]> {
+        debug_assert!(is_zst::<T>() || len == self.cap);
+        let slice = slice::from_raw_parts_mut(self.ptr(), len);
+        mem::forget(self);
+        Box::from_raw(slice)
+    }
+}
+
+impl<T, A: Allocator> RawBuffer<T, A> {
+    #[inline(always)]
+    pub const fn new_in(alloc: A) -> Self {
+        RawBuffer {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            alloc,
+            marker: PhantomData,
+        }
+    }
+    #[inline]
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
+        handle_reserve(Self::try_with_capacity_in(cap, alloc))
+    }
+    #[inline]
+    pub fn try_with_capacity_in(cap: usize, alloc: A) -> Result<Self, TryReserveError> {
+        let mut raw = RawBuffer::new_in(alloc);
+        if !is_zst::<T>() && cap != 0 {
+            raw.try_grow_to(cap)?;
+        }
+        Ok(raw)
+    }
+    /// # Safety
+    ///
+    /// `ptr` must be non-null and either dangling with `cap == 0`, or
+    /// allocated by `alloc` for exactly `cap` elements of `T`.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts_in(ptr: *mut T, cap: usize, alloc: A) -> Self {
+        RawBuffer {
+            ptr: NonNull::new_unchecked(ptr),
+            cap: if is_zst::<T>() { 0 } else { cap },
+            alloc,
+            marker: PhantomData,
+        }
+    }
+    #[inline(always)]
+    pub fn ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+    #[inline(always)]
+    pub fn cap(&self) -> usize {
+        if is_zst::<T>() {
+            usize::MAX
+        } else {
+            self.cap
+        }
+    }
+    #[inline(always)]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+    #[inline]
+    pub fn reserve(&mut self, used: usize, additional: usize) {
+        handle_reserve(self.try_reserve(used, additional))
+    }
+    #[inline]
+    pub fn try_reserve(&mut self, used: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.cap().wrapping_sub(used) >= additional {
+            return Ok(());
+        }
+        let required = used.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        let new_cap = cmp::max(self.cap.saturating_mul(2), required);
+        self.try_grow_to(cmp::max(min_non_zero_cap(mem::size_of::<T>()), new_cap))
+    }
+    #[inline]
+    pub fn reserve_exact(&mut self, used: usize, additional: usize) {
+        handle_reserve(self.try_reserve_exact(used, additional))
+    }
+    #[inline]
+    pub fn try_reserve_exact(&mut self, used: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.cap().wrapping_sub(used) >= additional {
+            return Ok(());
+        }
+        let required = used.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        self.try_grow_to(required)
+    }
+    #[inline]
+    pub fn double(&mut self) {
+        handle_reserve(self.try_double())
+    }
+    #[inline]
+    pub fn try_double(&mut self) -> Result<(), TryReserveError> {
+        if is_zst::<T>() {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        let new_cap = if self.cap == 0 {
+            min_non_zero_cap(mem::size_of::<T>())
+        } else {
+            self.cap.checked_mul(2).ok_or(TryReserveError::CapacityOverflow)?
+        };
+        self.try_grow_to(new_cap)
+    }
+    #[inline]
+    pub fn shrink_to_fit(&mut self, amount: usize) {
+        assert!(amount <= self.cap(), "Tried to shrink to a larger capacity");
+
+        if is_zst::<T>() || amount == self.cap {
+            return;
+        }
+        if amount == 0 {
+            unsafe {
+                self.dealloc_buffer();
+            }
+            self.ptr = NonNull::dangling();
+            self.cap = 0;
+        } else {
+            unsafe {
+                let old_layout = Layout::array::<T>(self.cap).unwrap_unchecked();
+                let new_layout = Layout::array::<T>(amount).unwrap_unchecked();
+                let ptr = self.alloc.shrink(self.ptr.cast(), old_layout, new_layout)
+                    .unwrap_or_else(|_| handle_alloc_error(new_layout));
+                self.ptr = ptr.cast();
+            }
+            self.cap = amount;
+        }
+    }
+    #[inline]
+    pub fn into_raw_parts_with_alloc(self) -> (*mut T, usize, A) {
+        unsafe {
+            let ptr = self.ptr();
+            let cap = self.cap;
+            let alloc = ptr::read(&self.alloc);
+            mem::forget(self);
+            (ptr, cap, alloc)
+        }
+    }
+
+    #[inline]
+    fn try_grow_to(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        let new_layout = Layout::array::<T>(new_cap).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        let ptr = unsafe {
+            if self.cap == 0 {
+                self.alloc.allocate(new_layout)
+            } else {
+                let old_layout = Layout::array::<T>(self.cap).unwrap_unchecked();
+                self.alloc.grow(self.ptr.cast(), old_layout, new_layout)
+            }
+        };
+
+        self.ptr = ptr.map_err(|_| TryReserveError::AllocError { layout: new_layout })?.cast();
+        self.cap = new_cap;
+        Ok(())
+    }
+    #[inline]
+    unsafe fn dealloc_buffer(&mut self) {
+        if !is_zst::<T>() && self.cap != 0 {
+            self.alloc.deallocate(self.ptr.cast(), Layout::array::<T>(self.cap).unwrap_unchecked());
+        }
+    }
+}
+
+impl<T, A: Allocator + Default> Default for RawBuffer<T, A> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new_in(A::default())
+    }
+}
+
+impl<T, A: Allocator> Drop for RawBuffer<T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            self.dealloc_buffer();
+        }
+    }
+}
+
+
+#[inline(always)]
+fn is_zst<T>() -> bool {
+    mem::size_of::<T>() == 0
+}
+
+#[inline(always)]
+pub(crate) fn min_non_zero_cap(elem_size: usize) -> usize {
+    match elem_size {
+        1 => 8,
+        n if n <= 1024 => 4,
+        _ => 1,
+    }
+}
+
+#[inline(always)]
+pub(crate) fn handle_reserve<R>(result: Result<R, TryReserveError>) -> R {
+    match result {
+        Ok(value) => value,
+        Err(TryReserveError::CapacityOverflow) => capacity_overflow(),
+        Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+    }
+}
+
+#[cold]
+#[inline(never)]
+pub(crate) fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
@@ -0,0 +1,62 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::Serializer;
+
+use super::allocator::Allocator;
+use super::growth_policy::GrowthPolicy;
+use super::serde_impls::cautious_size_hint;
+use super::vector::Vector;
+
+
+#[inline]
+pub fn serialize<A, G, S>(bytes: &Vector<u8, A, G>, serializer: S) -> Result<S::Ok, S::Error>
+    where A: Allocator,
+          G: GrowthPolicy,
+          S: Serializer,
+{
+    serializer.serialize_bytes(bytes.as_slice())
+}
+
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vector<u8>, D::Error> {
+    deserializer.deserialize_byte_buf(BytesVisitor)
+}
+
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vector<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+    #[inline]
+    fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Vector<u8>, E> {
+        Ok(Vector::from(bytes))
+    }
+    #[inline]
+    fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Vector<u8>, E> {
+        Ok(Vector::from(bytes))
+    }
+    #[inline]
+    fn visit_str<E: Error>(self, string: &str) -> Result<Vector<u8>, E> {
+        Ok(Vector::from(string))
+    }
+    #[inline]
+    fn visit_string<E: Error>(self, string: String) -> Result<Vector<u8>, E> {
+        Ok(Vector::from(string))
+    }
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Vector<u8>, S::Error> {
+        let mut bytes = Vector::with_capacity(cautious_size_hint::<u8>(seq.size_hint()));
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
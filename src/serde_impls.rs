@@ -0,0 +1,91 @@
+use core::{cmp, fmt, mem};
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use super::allocator::Allocator;
+use super::growth_policy::GrowthPolicy;
+use super::vector::Vector;
+
+
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+#[inline]
+pub(crate) fn cautious_size_hint<T>(hint: Option<usize>) -> usize {
+    match mem::size_of::<T>() {
+        0 => 0,
+        size => cmp::min(hint.unwrap_or(0), MAX_PREALLOC_BYTES / size),
+    }
+}
+
+
+impl<T: Serialize, A: Allocator, G: GrowthPolicy> Serialize for Vector<T, A, G> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+impl<'de, T, A, G> Deserialize<'de> for Vector<T, A, G>
+    where T: Deserialize<'de>,
+          A: Allocator + Default,
+          G: GrowthPolicy + Default,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(VectorVisitor(PhantomData))
+    }
+    #[inline]
+    fn deserialize_in_place<D: Deserializer<'de>>(deserializer: D, place: &mut Self) -> Result<(), D::Error> {
+        place.clear();
+        deserializer.deserialize_seq(VectorInPlaceVisitor(place))
+    }
+}
+
+
+struct VectorVisitor<T, A: Allocator, G: GrowthPolicy>(PhantomData<Vector<T, A, G>>);
+
+impl<'de, T, A, G> Visitor<'de> for VectorVisitor<T, A, G>
+    where T: Deserialize<'de>,
+          A: Allocator + Default,
+          G: GrowthPolicy + Default,
+{
+    type Value = Vector<T, A, G>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let mut vector = Vector::default();
+        vector.reserve(cautious_size_hint::<T>(seq.size_hint()));
+
+        while let Some(element) = seq.next_element()? {
+            vector.push(element);
+        }
+        Ok(vector)
+    }
+}
+
+
+struct VectorInPlaceVisitor<'a, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy>(&'a mut Vector<T, A, G>);
+
+impl<'a, 'de, T, A, G> Visitor<'de> for VectorInPlaceVisitor<'a, T, A, G>
+    where T: Deserialize<'de>,
+          A: Allocator,
+          G: GrowthPolicy,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<(), S::Error> {
+        self.0.reserve(cautious_size_hint::<T>(seq.size_hint()));
+
+        while let Some(element) = seq.next_element()? {
+            self.0.push(element);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+use alloc::alloc::{handle_alloc_error, Layout};
+
+use core::{cmp, fmt, mem, ptr, slice};
+use core::borrow::Borrow;
+use core::cell::Cell;
+use core::hash::{self, Hash};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::NonNull;
+use core::sync::atomic::{self, AtomicUsize, Ordering};
+
+use collection_traits::*;
+
+use super::allocator::{Allocator, Global};
+use super::raw_buffer::capacity_overflow;
+use super::vector::Vector;
+
+
+/// # Safety
+///
+/// `decrement` must return `true` only once the last reference is released,
+/// and `is_unique` must only return `true` when no other reference exists.
+pub unsafe trait RefCount {
+    fn one() -> Self;
+    fn get(&self) -> usize;
+    fn increment(&self);
+    fn decrement(&self) -> bool;
+    fn is_unique(&self) -> bool;
+}
+
+unsafe impl RefCount for Cell<usize> {
+    #[inline(always)]
+    fn one() -> Self {
+        Cell::new(1)
+    }
+    #[inline(always)]
+    fn get(&self) -> usize {
+        Cell::get(self)
+    }
+    #[inline]
+    fn increment(&self) {
+        let count = Cell::get(self).checked_add(1).expect("reference count overflow");
+        self.set(count);
+    }
+    #[inline]
+    fn decrement(&self) -> bool {
+        let count = Cell::get(self) - 1;
+        self.set(count);
+        count == 0
+    }
+    #[inline(always)]
+    fn is_unique(&self) -> bool {
+        Cell::get(self) == 1
+    }
+}
+
+unsafe impl RefCount for AtomicUsize {
+    #[inline(always)]
+    fn one() -> Self {
+        AtomicUsize::new(1)
+    }
+    #[inline(always)]
+    fn get(&self) -> usize {
+        self.load(Ordering::Acquire)
+    }
+    #[inline]
+    fn increment(&self) {
+        if self.fetch_add(1, Ordering::Relaxed) > isize::MAX as usize {
+            self.fetch_sub(1, Ordering::Relaxed);
+            panic!("reference count overflow");
+        }
+    }
+    #[inline]
+    fn decrement(&self) -> bool {
+        if self.fetch_sub(1, Ordering::Release) == 1 {
+            atomic::fence(Ordering::Acquire);
+            true
+        } else {
+            false
+        }
+    }
+    #[inline(always)]
+    fn is_unique(&self) -> bool {
+        self.load(Ordering::Acquire) == 1
+    }
+}
+
+
+pub type ArcVector<T> = SharedVector<T, AtomicUsize>;
+pub type RcVector<T> = SharedVector<T, Cell<usize>>;
+
+// The header lives after the elements so a unique buffer can be handed to
+// `Vector` as is; the bytes it occupies become spare capacity.
+struct Header<C> {
+    count: C,
+    len: usize,
+    cap: usize,
+}
+
+pub struct SharedVector<T, C: RefCount> {
+    data: NonNull<T>,
+    header: NonNull<Header<C>>,
+    marker: PhantomData<(T, Header<C>)>,
+}
+
+unsafe impl<T: Send + Sync> Send for SharedVector<T, AtomicUsize> {}
+unsafe impl<T: Send + Sync> Sync for SharedVector<T, AtomicUsize> {}
+
+impl<T, C: RefCount> SharedVector<T, C> {
+    #[inline]
+    pub fn new() -> Self {
+        unsafe { Self::allocate(0) }
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.header().len
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.header().len == 0
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.header().len) }
+    }
+    #[inline(always)]
+    pub fn strong_count(this: &Self) -> usize {
+        this.header().count.get()
+    }
+    #[inline(always)]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.header == other.header
+    }
+    #[inline(always)]
+    pub fn is_unique(this: &Self) -> bool {
+        this.header().count.is_unique()
+    }
+    #[inline]
+    pub fn get_mut(this: &mut Self) -> Option<&mut [T]> {
+        if Self::is_unique(this) {
+            Some(unsafe { this.as_mut_slice() })
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn try_unwrap(this: Self) -> Result<Vector<T>, Self> {
+        if !Self::is_unique(&this) {
+            return Err(this);
+        }
+
+        let this = mem::ManuallyDrop::new(this);
+        let (len, cap) = (this.header().len, this.header().cap);
+        let layout = Self::layout(cap);
+
+        unsafe {
+            if mem::size_of::<T>() == 0 {
+                Global.deallocate(this.header.cast(), layout);
+                let mut vector = Vector::new();
+                vector.set_len(len);
+                Ok(vector)
+            } else {
+                ptr::drop_in_place(this.header.as_ptr());
+                let capacity = layout.size() / mem::size_of::<T>();
+                Ok(Vector::from_raw_parts(this.data.as_ptr(), len, capacity))
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn header(&self) -> &Header<C> {
+        unsafe { self.header.as_ref() }
+    }
+    #[inline(always)]
+    unsafe fn as_mut_slice(&mut self) -> &mut [T] {
+        slice::from_raw_parts_mut(self.data.as_ptr(), self.header().len)
+    }
+    #[inline]
+    fn header_slots() -> usize {
+        let header = Layout::new::<Header<C>>();
+        match mem::size_of::<T>() {
+            0 => 0,
+            size => (header.size() + header.align() - 1).div_ceil(size),
+        }
+    }
+    #[inline]
+    fn layout(cap: usize) -> Layout {
+        if mem::size_of::<T>() == 0 {
+            Layout::new::<Header<C>>()
+        } else {
+            cap.checked_add(Self::header_slots())
+                .and_then(|slots| Layout::array::<T>(slots).ok())
+                .unwrap_or_else(|| capacity_overflow())
+        }
+    }
+    #[inline]
+    unsafe fn from_buffer(data: NonNull<T>, len: usize, cap: usize) -> Self {
+        let header = if mem::size_of::<T>() == 0 {
+            data.cast::<Header<C>>()
+        } else {
+            let end = data.as_ptr().add(cap) as *mut u8;
+            let align = mem::align_of::<Header<C>>();
+            let pad = end.addr().wrapping_neg() & (align - 1);
+            NonNull::new_unchecked(end.add(pad) as *mut Header<C>)
+        };
+        ptr::write(header.as_ptr(), Header { count: C::one(), len, cap });
+
+        SharedVector {
+            data: if mem::size_of::<T>() == 0 { NonNull::dangling() } else { data },
+            header,
+            marker: PhantomData,
+        }
+    }
+    #[inline]
+    unsafe fn allocate(cap: usize) -> Self {
+        let layout = Self::layout(cap);
+        let ptr = Global.allocate(layout).unwrap_or_else(|_| handle_alloc_error(layout));
+        Self::from_buffer(ptr.cast(), 0, cap)
+    }
+}
+
+impl<T: Clone, C: RefCount> SharedVector<T, C> {
+    #[inline]
+    pub fn make_mut(this: &mut Self) -> &mut [T] {
+        if !Self::is_unique(this) {
+            *this = SharedVector::from(this.as_slice());
+        }
+        unsafe { this.as_mut_slice() }
+    }
+    #[inline]
+    pub fn into_vector(this: Self) -> Vector<T> {
+        match Self::try_unwrap(this) {
+            Ok(vector) => vector,
+            Err(this) => Vector::from(this.as_slice()),
+        }
+    }
+}
+
+
+impl<T, C: RefCount> Drop for SharedVector<T, C> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.header().count.decrement() {
+            let layout = Self::layout(self.header().cap);
+
+            unsafe {
+                let base = if mem::size_of::<T>() == 0 {
+                    self.header.cast()
+                } else {
+                    self.data.cast()
+                };
+                let elements = self.as_mut_slice() as *mut [T];
+
+                let _guard = DeallocOnDrop { ptr: base, layout };
+                ptr::drop_in_place(self.header.as_ptr());
+                ptr::drop_in_place(elements);
+            }
+        }
+    }
+}
+
+struct DeallocOnDrop {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Drop for DeallocOnDrop {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            Global.deallocate(self.ptr, self.layout);
+        }
+    }
+}
+
+impl<T, C: RefCount> Clone for SharedVector<T, C> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        self.header().count.increment();
+        SharedVector {
+            data: self.data,
+            header: self.header,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, C: RefCount> Default for SharedVector<T, C> {
+    #[inline(always)]
+    fn default() -> Self {
+        SharedVector::new()
+    }
+}
+
+impl<T, C: RefCount> Deref for SharedVector<T, C> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, C: RefCount> AsRef<[T]> for SharedVector<T, C> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, C: RefCount> Borrow<[T]> for SharedVector<T, C> {
+    #[inline(always)]
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: fmt::Debug, C: RefCount> fmt::Debug for SharedVector<T, C> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq, C: RefCount> PartialEq for SharedVector<T, C> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, C: RefCount> Eq for SharedVector<T, C> {}
+
+impl<T: PartialOrd, C: RefCount> PartialOrd for SharedVector<T, C> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, C: RefCount> Ord for SharedVector<T, C> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, C: RefCount> Hash for SharedVector<T, C> {
+    #[inline(always)]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, C: RefCount> From<Vector<T>> for SharedVector<T, C> {
+    #[inline]
+    fn from(vector: Vector<T>) -> Self {
+        let len = vector.len();
+
+        if mem::size_of::<T>() != 0 && vector.capacity() - len >= Self::header_slots() {
+            let (ptr, len, capacity) = vector.into_raw_parts();
+            unsafe {
+                Self::from_buffer(NonNull::new_unchecked(ptr), len, capacity - Self::header_slots())
+            }
+        } else {
+            let mut vector = vector;
+            unsafe {
+                let shared = Self::allocate(len);
+                ptr::copy_nonoverlapping(vector.as_ptr(), shared.data.as_ptr(), len);
+                (*shared.header.as_ptr()).len = len;
+                vector.set_len(0);
+                shared
+            }
+        }
+    }
+}
+
+impl<'a, T: Clone, C: RefCount> From<&'a [T]> for SharedVector<T, C> {
+    #[inline]
+    fn from(slice: &'a [T]) -> Self {
+        let mut vector = Vector::with_capacity(slice.len() + Self::header_slots());
+        vector.extend_from_slice(slice);
+        SharedVector::from(vector)
+    }
+}
+
+impl<T, C: RefCount> FromIterator<T> for SharedVector<T, C> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SharedVector::from(iter.into_iter().collect::<Vector<T>>())
+    }
+}
+
+impl<'a, T, C: RefCount> IntoIterator for &'a SharedVector<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, C: RefCount> Collection for SharedVector<T, C> {
+    #[inline(always)]
+    fn len(&self) -> usize { self.header().len }
+}
+
+impl<'a, T: 'a, C: RefCount> Iterable<'a, &'a T> for SharedVector<T, C> {
+    type Iter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        self.as_slice().iter()
+    }
+}
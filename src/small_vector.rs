@@ -0,0 +1,819 @@
+use core::{cmp, fmt, ptr, slice, mem};
+use core::ops::*;
+use core::ptr::NonNull;
+use core::mem::MaybeUninit;
+use core::hash::{self, Hash};
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, FromIterator};
+
+use collection_traits::*;
+
+use super::allocator::Global;
+use super::raw_buffer::{handle_reserve, RawBuffer, TryReserveError};
+use super::vector::{range_bounds, retain_raw, Vector};
+
+
+enum Data<T, const N: usize> {
+    Inline(MaybeUninit<[T; N]>),
+    Heap(RawBuffer<T>),
+}
+
+pub struct SmallVector<T, const N: usize> {
+    data: Data<T, N>,
+    len: usize,
+}
+
+unsafe impl<T: Send, const N: usize> Send for SmallVector<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for SmallVector<T, N> {}
+
+impl<T, const N: usize> SmallVector<T, N> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        SmallVector {
+            data: Data::Inline(MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+    #[inline]
+    pub fn with_capacity(cap: usize) -> Self {
+        if cap <= inline_capacity::<T, N>() {
+            Self::new()
+        } else {
+            SmallVector {
+                data: Data::Heap(RawBuffer::with_capacity(cap)),
+                len: 0,
+            }
+        }
+    }
+    #[inline(always)]
+    pub fn inline_size(&self) -> usize {
+        inline_capacity::<T, N>()
+    }
+    #[inline(always)]
+    pub fn spilled(&self) -> bool {
+        match self.data {
+            Data::Inline(_) => false,
+            Data::Heap(_) => true,
+        }
+    }
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        match self.data {
+            Data::Inline(_) => inline_capacity::<T, N>(),
+            Data::Heap(ref raw) => raw.cap(),
+        }
+    }
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional))
+    }
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve_exact(additional))
+    }
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        match self.data {
+            Data::Heap(ref mut raw) => raw.try_reserve(self.len, additional),
+            Data::Inline(_) => {
+                let required = self.len.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+                self.try_spill(cmp::max(required, inline_capacity::<T, N>().saturating_mul(2)))
+            },
+        }
+    }
+    #[inline]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        match self.data {
+            Data::Heap(ref mut raw) => raw.try_reserve_exact(self.len, additional),
+            Data::Inline(_) => {
+                let required = self.len.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+                self.try_spill(required)
+            },
+        }
+    }
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        let len = self.len;
+
+        if let Data::Heap(ref mut raw) = self.data {
+            if len > inline_capacity::<T, N>() {
+                raw.shrink_to_fit(len);
+                return;
+            }
+        } else {
+            return;
+        }
+
+        let mut inline = MaybeUninit::<[T; N]>::uninit();
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), inline.as_mut_ptr() as *mut T, len);
+        }
+        self.data = Data::Inline(inline);
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self
+    }
+    #[inline(always)]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        self
+    }
+    /// # Safety
+    ///
+    /// `len` must not exceed the capacity, and the first `len` elements must
+    /// be initialized.
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, len: usize) {
+        self.len = len;
+    }
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        if self.len == self.capacity() {
+            self.reserve(1);
+        }
+        unsafe {
+            ptr::write(self.as_mut_ptr().add(self.len), element);
+            self.len += 1;
+        }
+    }
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            unsafe {
+                self.len -= 1;
+                Some(ptr::read(self.as_ptr().add(self.len)))
+            }
+        }
+    }
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len;
+        assert!(index <= len, "insertion index (is {}) should be <= len (is {})", index, len);
+
+        if len == self.capacity() {
+            self.reserve(1);
+        }
+
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            ptr::copy(p, p.add(1), len - index);
+            ptr::write(p, element);
+            self.len += 1;
+        }
+    }
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "removal index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            let ret = ptr::read(p);
+            ptr::copy(p.add(1), p, len - index - 1);
+            self.len -= 1;
+            ret
+        }
+    }
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "swap_remove index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let p = self.as_mut_ptr();
+            let value = ptr::read(p.add(index));
+            ptr::copy(p.add(len - 1), p.add(index), 1);
+            self.len -= 1;
+            value
+        }
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), self.len - len);
+            self.len = len;
+            ptr::drop_in_place(tail);
+        }
+    }
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&T) -> bool
+    {
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            retain_raw(ptr, &mut self.len, |element| f(element));
+        }
+    }
+    #[inline]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+        where R: RangeBounds<usize>
+    {
+        let len = self.len;
+        let (start, end) = range_bounds(&range, len);
+
+        unsafe {
+            self.set_len(start);
+            let vec = NonNull::from(&mut *self);
+            let range_slice = slice::from_raw_parts(Self::data_ptr(vec.as_ptr()).add(start), end - start);
+            Drain {
+                tail_start: end,
+                tail_len: len - end,
+                iter: range_slice.iter(),
+                vec,
+            }
+        }
+    }
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "`at` out of bounds");
+
+        let other_len = self.len - at;
+        let mut other = Self::with_capacity(other_len);
+
+        unsafe {
+            self.set_len(at);
+            other.set_len(other_len);
+
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), other_len);
+        }
+        other
+    }
+    #[inline]
+    pub fn into_vector(self) -> Vector<T> {
+        let mut this = mem::ManuallyDrop::new(self);
+        let len = this.len;
+
+        match this.data {
+            Data::Heap(ref mut raw) => unsafe {
+                Vector::from_raw_buffer(ptr::read(raw), len)
+            },
+            Data::Inline(ref buf) => unsafe {
+                let raw = RawBuffer::with_capacity(len);
+                ptr::copy_nonoverlapping(buf.as_ptr() as *const T, raw.ptr(), len);
+                Vector::from_raw_buffer(raw, len)
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn as_ptr(&self) -> *const T {
+        match self.data {
+            Data::Inline(ref buf) => buf.as_ptr() as *const T,
+            Data::Heap(ref raw) => raw.ptr(),
+        }
+    }
+    #[inline(always)]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        match self.data {
+            Data::Inline(ref mut buf) => buf.as_mut_ptr() as *mut T,
+            Data::Heap(ref raw) => raw.ptr(),
+        }
+    }
+    #[inline(always)]
+    unsafe fn data_ptr(this: *mut Self) -> *mut T {
+        match *ptr::addr_of_mut!((*this).data) {
+            Data::Inline(ref mut buf) => buf.as_mut_ptr() as *mut T,
+            Data::Heap(ref raw) => raw.ptr(),
+        }
+    }
+    #[inline]
+    fn try_spill(&mut self, cap: usize) -> Result<(), TryReserveError> {
+        if cap <= self.capacity() {
+            return Ok(());
+        }
+
+        let raw = RawBuffer::try_with_capacity_in(cap, Global)?;
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), raw.ptr(), self.len);
+        }
+        self.data = Data::Heap(raw);
+        Ok(())
+    }
+    #[inline]
+    fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iterator: I) {
+        while let Some(element) = iterator.next() {
+            let len = self.len;
+            if len == self.capacity() {
+                let (lower, _) = iterator.size_hint();
+                self.reserve(lower.saturating_add(1));
+            }
+            unsafe {
+                ptr::write(self.as_mut_ptr().add(len), element);
+                self.set_len(len + 1);
+            }
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> SmallVector<T, N> {
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        let len = self.len;
+
+        if new_len > len {
+            self.reserve(new_len - len);
+            for _ in len + 1..new_len {
+                self.push(value.clone());
+            }
+            self.push(value);
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallVector<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVector<T, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(&mut self[..]);
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallVector<T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut vector = Self::with_capacity(self.len);
+        vector.extend(self.iter().cloned());
+        vector
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<SmallVector<U, M>> for SmallVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &SmallVector<U, M>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<'b, T: PartialEq<U>, U, const N: usize> PartialEq<&'b [U]> for SmallVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &&'b [U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize, const M: usize> PartialEq<[U; M]> for SmallVector<T, N> {
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallVector<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallVector<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SmallVector<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for SmallVector<T, N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVector<T, N> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr(), self.len)
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallVector<T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.as_mut_ptr(), self.len)
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>, const N: usize> Index<I> for SmallVector<T, N> {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &I::Output {
+        Index::index(&**self, index)
+    }
+}
+
+impl<T, I: slice::SliceIndex<[T]>, const N: usize> IndexMut<I> for SmallVector<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        IndexMut::index_mut(&mut **self, index)
+    }
+}
+
+impl<T, const N: usize> Collection for SmallVector<T, N> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> CollectionMut for SmallVector<T, N> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        SmallVector::clear(self)
+    }
+}
+
+impl<T, const N: usize> InsertMut<usize, T> for SmallVector<T, N> {
+    type Output = ();
+
+    #[inline(always)]
+    fn insert(&mut self, index: usize, element: T) -> Self::Output {
+        SmallVector::insert(self, index, element)
+    }
+}
+
+impl<T, const N: usize> RemoveMut<usize> for SmallVector<T, N> {
+    type Output = T;
+
+    #[inline(always)]
+    fn remove(&mut self, index: usize) -> T {
+        SmallVector::remove(self, index)
+    }
+}
+
+impl<T, const N: usize> DequeMut<T> for SmallVector<T, N> {
+    #[inline(always)]
+    fn push_front(&mut self, element: T) {
+        self.insert(0, element);
+    }
+    #[inline(always)]
+    fn push_back(&mut self, element: T) {
+        SmallVector::push(self, element)
+    }
+    #[inline(always)]
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            Some(self.remove(0))
+        }
+    }
+    #[inline(always)]
+    fn pop_back(&mut self) -> Option<T> {
+        SmallVector::pop(self)
+    }
+    #[inline(always)]
+    fn front(&self) -> Option<&T> {
+        self.first()
+    }
+    #[inline(always)]
+    fn back(&self) -> Option<&T> {
+        self.last()
+    }
+    #[inline(always)]
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.first_mut()
+    }
+    #[inline(always)]
+    fn back_mut(&mut self) -> Option<&mut T> {
+        self.last_mut()
+    }
+}
+
+impl<T, const N: usize> StackMut<T> for SmallVector<T, N> {
+    #[inline(always)]
+    fn push(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> { self.pop_back() }
+    #[inline(always)]
+    fn top(&self) -> Option<&T> { self.back() }
+    #[inline(always)]
+    fn top_mut(&mut self) -> Option<&mut T> { self.back_mut() }
+}
+
+impl<T, const N: usize> QueueMut<T> for SmallVector<T, N> {
+    #[inline(always)]
+    fn enqueue(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn dequeue(&mut self) -> Option<T> { self.pop_front() }
+    #[inline(always)]
+    fn peek(&self) -> Option<&T> { self.front() }
+    #[inline(always)]
+    fn peek_mut(&mut self) -> Option<&mut T> { self.front_mut() }
+}
+
+impl<'a, T: 'a, const N: usize> Iterable<'a, &'a T> for SmallVector<T, N> {
+    type Iter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        (**self).iter()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> IterableMut<'a, &'a mut T> for SmallVector<T, N> {
+    type IterMut = slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        (**self).iter_mut()
+    }
+}
+
+impl<'a, T: 'a, const N: usize> SeqMut<'a, T> for SmallVector<T, N> {}
+
+
+impl<T, const N: usize> FromIterator<T> for SmallVector<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Self::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallVector<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_desugared(iter.into_iter())
+    }
+}
+
+impl<'a, T: 'a + Copy, const N: usize> Extend<&'a T> for SmallVector<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend_desugared(iter.into_iter().cloned())
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVector<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallVector<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    #[inline]
+    fn into_iter(mut self) -> IntoIter<T, N> {
+        let end = self.len;
+        self.len = 0;
+        IntoIter {
+            vector: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for SmallVector<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for SmallVector<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<'a, T: Clone, const N: usize> From<&'a [T]> for SmallVector<T, N> {
+    #[inline]
+    fn from(s: &'a [T]) -> Self {
+        s.iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> From<Vector<T>> for SmallVector<T, N> {
+    #[inline]
+    fn from(vector: Vector<T>) -> Self {
+        let (raw, len) = vector.into_raw_buffer();
+        SmallVector {
+            data: Data::Heap(raw),
+            len,
+        }
+    }
+}
+
+impl<T, const N: usize> From<SmallVector<T, N>> for Vector<T> {
+    #[inline(always)]
+    fn from(vector: SmallVector<T, N>) -> Vector<T> {
+        vector.into_vector()
+    }
+}
+
+
+pub struct IntoIter<T, const N: usize> {
+    vector: SmallVector<T, N>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.vector.as_ptr().add(self.start), self.end - self.start)
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            let index = self.start;
+            self.start += 1;
+            unsafe {
+                Some(ptr::read(self.vector.as_ptr().add(index)))
+            }
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            unsafe {
+                Some(ptr::read(self.vector.as_ptr().add(self.end)))
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = ptr::slice_from_raw_parts_mut(
+                self.vector.as_mut_ptr().add(self.start),
+                self.end - self.start,
+            );
+            self.start = self.end;
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+
+pub struct Drain<'a, T: 'a, const N: usize> {
+    tail_start: usize,
+    tail_len: usize,
+    iter: slice::Iter<'a, T>,
+    vec: NonNull<SmallVector<T, N>>,
+}
+
+unsafe impl<'a, T: Sync, const N: usize> Sync for Drain<'a, T, N> {}
+unsafe impl<'a, T: Send, const N: usize> Send for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    fn drop(&mut self) {
+        struct DropGuard<'r, 'a: 'r, T: 'a, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<'r, 'a, T, const N: usize> Drop for DropGuard<'r, 'a, T, N> {
+            fn drop(&mut self) {
+                if self.0.tail_len > 0 {
+                    unsafe {
+                        let vec = self.0.vec.as_ptr();
+                        let len = ptr::addr_of_mut!((*vec).len);
+                        let start = *len;
+                        let tail = self.0.tail_start;
+                        if tail != start {
+                            let p = SmallVector::data_ptr(vec);
+                            ptr::copy(p.add(tail), p.add(start), self.0.tail_len);
+                        }
+                        *len = start + self.0.tail_len;
+                    }
+                }
+            }
+        }
+
+        let iter = mem::take(&mut self.iter);
+        let drop_len = iter.len();
+        let vec = self.vec;
+
+        let _guard = DropGuard(self);
+
+        if drop_len == 0 {
+            return;
+        }
+
+        unsafe {
+            // take the remaining elements' address before `data_ptr` reborrows the inline buffer
+            let drop_ptr = iter.as_slice().as_ptr();
+            let vec_ptr = SmallVector::data_ptr(vec.as_ptr());
+            let drop_offset = if mem::size_of::<T>() == 0 {
+                0
+            } else {
+                drop_ptr.offset_from(vec_ptr) as usize
+            };
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(vec_ptr.add(drop_offset), drop_len));
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+
+#[inline(always)]
+fn inline_capacity<T, const N: usize>() -> usize {
+    if mem::size_of::<T>() == 0 {
+        usize::MAX
+    } else {
+        N
+    }
+}
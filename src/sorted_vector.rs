@@ -0,0 +1,401 @@
+use core::{cmp, fmt, mem, slice};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::iter::FromIterator;
+use core::ops::{Bound, Deref, RangeBounds};
+
+use collection_traits::*;
+
+use super::vector::{self, Vector};
+
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVector<T> {
+    vector: Vector<T>,
+}
+
+impl<T> SortedVector<T> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        SortedVector { vector: Vector::new() }
+    }
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        SortedVector { vector: Vector::with_capacity(capacity) }
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.vector.is_empty()
+    }
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.vector.capacity()
+    }
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vector.reserve(additional);
+    }
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.vector.shrink_to_fit();
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.vector.clear();
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vector
+    }
+    #[inline(always)]
+    pub fn into_vector(self) -> Vector<T> {
+        self.vector
+    }
+    #[inline(always)]
+    pub fn select(&self, index: usize) -> Option<&T> {
+        self.vector.get(index)
+    }
+    #[inline(always)]
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.vector.is_empty() {
+            None
+        } else {
+            Some(self.vector.remove(0))
+        }
+    }
+    #[inline(always)]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.vector.pop()
+    }
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+        where F: FnMut(&T) -> bool,
+    {
+        self.vector.retain(f);
+    }
+}
+
+impl<T: Ord> SortedVector<T> {
+    #[inline]
+    pub fn from_vector(mut vector: Vector<T>) -> Self {
+        vector.sort();
+        SortedVector { vector }
+    }
+    #[inline]
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.vector.partition_point(|x| x <= &value);
+        self.vector.insert(index, value);
+        index
+    }
+    #[inline]
+    pub fn insert_unique(&mut self, value: T) -> Result<usize, T> {
+        match self.vector.binary_search(&value) {
+            Ok(_) => Err(value),
+            Err(index) => {
+                self.vector.insert(index, value);
+                Ok(index)
+            },
+        }
+    }
+    #[inline]
+    pub fn remove<Q>(&mut self, value: &Q) -> Option<T>
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(value).map(|index| self.vector.remove(index))
+    }
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(value).is_some()
+    }
+    #[inline]
+    pub fn index_of<Q>(&self, value: &Q) -> Option<usize>
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        let index = self.rank(value);
+        match self.vector.get(index) {
+            Some(x) if x.borrow() == value => Some(index),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn rank<Q>(&self, value: &Q) -> usize
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        lower_bound(&self.vector, value, |x| x.borrow())
+    }
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+        where T: Borrow<Q>,
+              Q: Ord + ?Sized,
+              R: RangeBounds<Q>,
+    {
+        let (start, end) = range_indices(&self.vector, range, |x| x.borrow());
+        &self.vector[start..end]
+    }
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.vector.dedup();
+    }
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self
+        where T: Clone,
+    {
+        merge_slices(&self.vector, &other.vector, true, true, true)
+    }
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self
+        where T: Clone,
+    {
+        merge_slices(&self.vector, &other.vector, false, true, false)
+    }
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self
+        where T: Clone,
+    {
+        merge_slices(&self.vector, &other.vector, true, false, false)
+    }
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+        where T: Clone,
+    {
+        merge_slices(&self.vector, &other.vector, true, false, true)
+    }
+}
+
+
+#[inline]
+pub(crate) fn lower_bound<T, Q, F>(slice: &[T], value: &Q, key: F) -> usize
+    where Q: Ord + ?Sized,
+          F: Fn(&T) -> &Q,
+{
+    slice.partition_point(|x| key(x) < value)
+}
+
+#[inline]
+pub(crate) fn upper_bound<T, Q, F>(slice: &[T], value: &Q, key: F) -> usize
+    where Q: Ord + ?Sized,
+          F: Fn(&T) -> &Q,
+{
+    slice.partition_point(|x| key(x) <= value)
+}
+
+#[inline]
+pub(crate) fn range_indices<T, Q, R, F>(slice: &[T], range: R, key: F) -> (usize, usize)
+    where Q: Ord + ?Sized,
+          R: RangeBounds<Q>,
+          F: Fn(&T) -> &Q,
+{
+    let start = match range.start_bound() {
+        Bound::Included(value) => lower_bound(slice, value, &key),
+        Bound::Excluded(value) => upper_bound(slice, value, &key),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(value) => upper_bound(slice, value, &key),
+        Bound::Excluded(value) => lower_bound(slice, value, &key),
+        Bound::Unbounded => slice.len(),
+    };
+    (start, cmp::max(start, end))
+}
+
+pub(crate) fn merge_by<T, F>(left: Vector<T>, right: Vector<T>, mut compare: F) -> Vector<T>
+    where F: FnMut(&T, &T) -> Ordering,
+{
+    if right.is_empty() {
+        return left;
+    }
+    if left.is_empty() {
+        return right;
+    }
+
+    let mut merged = Vector::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    loop {
+        let take_left = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => compare(l, r) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+        let element = if take_left { left.next() } else { right.next() };
+        merged.push(element.unwrap());
+    }
+    merged
+}
+
+fn merge_slices<T: Ord + Clone>(
+    left: &[T],
+    right: &[T],
+    keep_left: bool,
+    keep_both: bool,
+    keep_right: bool,
+) -> SortedVector<T> {
+    let mut vector = Vector::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            Ordering::Less => {
+                if keep_left {
+                    vector.push(left[i].clone());
+                }
+                i += 1;
+            },
+            Ordering::Greater => {
+                if keep_right {
+                    vector.push(right[j].clone());
+                }
+                j += 1;
+            },
+            Ordering::Equal => {
+                if keep_both {
+                    vector.push(left[i].clone());
+                }
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+    if keep_left {
+        vector.extend_from_slice(&left[i..]);
+    }
+    if keep_right {
+        vector.extend_from_slice(&right[j..]);
+    }
+    SortedVector { vector }
+}
+
+
+impl<T> Default for SortedVector<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        SortedVector::new()
+    }
+}
+
+impl<T> Deref for SortedVector<T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        &self.vector
+    }
+}
+
+impl<T> AsRef<[T]> for SortedVector<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        &self.vector
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SortedVector<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.vector, f)
+    }
+}
+
+impl<T: Ord> From<Vector<T>> for SortedVector<T> {
+    #[inline(always)]
+    fn from(vector: Vector<T>) -> Self {
+        SortedVector::from_vector(vector)
+    }
+}
+
+impl<T> From<SortedVector<T>> for Vector<T> {
+    #[inline(always)]
+    fn from(sorted: SortedVector<T>) -> Self {
+        sorted.vector
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVector<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut other: Vector<T> = iter.into_iter().collect();
+        if other.is_empty() {
+            return;
+        }
+        other.sort();
+
+        let vector = mem::take(&mut self.vector);
+        self.vector = merge_by(vector, other, Ord::cmp);
+    }
+}
+
+impl<'a, T: 'a + Ord + Copy> Extend<&'a T> for SortedVector<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVector<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SortedVector::from_vector(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for SortedVector<T> {
+    type Item = T;
+    type IntoIter = vector::IntoIter<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedVector<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.iter()
+    }
+}
+
+impl<T> Collection for SortedVector<T> {
+    #[inline(always)]
+    fn len(&self) -> usize { self.vector.len() }
+}
+
+impl<T> CollectionMut for SortedVector<T> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.vector.clear()
+    }
+}
+
+impl<'b, T: Ord> RemoveMut<&'b T> for SortedVector<T> {
+    type Output = Option<T>;
+
+    #[inline(always)]
+    fn remove(&mut self, value: &'b T) -> Self::Output {
+        SortedVector::remove(self, value)
+    }
+}
+
+impl<'a, T: 'a> Iterable<'a, &'a T> for SortedVector<T> {
+    type Iter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        self.vector.iter()
+    }
+}
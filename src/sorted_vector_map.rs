@@ -0,0 +1,372 @@
+use core::{fmt, iter, mem, slice};
+use core::borrow::Borrow;
+use core::iter::FromIterator;
+use core::ops::{Index, RangeBounds};
+
+use collection_traits::*;
+
+use super::sorted_vector::{lower_bound, merge_by, range_indices};
+use super::vector::{self, Vector};
+
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVectorMap<K, V> {
+    entries: Vector<(K, V)>,
+}
+
+pub type Iter<'a, K, V> = iter::Map<slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> (&'a K, &'a V)>;
+pub type IterMut<'a, K, V> = iter::Map<slice::IterMut<'a, (K, V)>, fn(&'a mut (K, V)) -> (&'a K, &'a mut V)>;
+pub type Keys<'a, K, V> = iter::Map<slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> &'a K>;
+pub type Values<'a, K, V> = iter::Map<slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> &'a V>;
+pub type ValuesMut<'a, K, V> = iter::Map<slice::IterMut<'a, (K, V)>, fn(&'a mut (K, V)) -> &'a mut V>;
+
+impl<K, V> SortedVectorMap<K, V> {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        SortedVectorMap { entries: Vector::new() }
+    }
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        SortedVectorMap { entries: Vector::with_capacity(capacity) }
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional);
+    }
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit();
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.entries
+    }
+    #[inline(always)]
+    pub fn into_vector(self) -> Vector<(K, V)> {
+        self.entries
+    }
+    #[inline(always)]
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(entry)
+    }
+    #[inline(always)]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.select(0)
+    }
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(entry)
+    }
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.entries.iter().map(entry as fn(&(K, V)) -> (&K, &V))
+    }
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.entries.iter_mut().map(entry_mut as fn(&mut (K, V)) -> (&K, &mut V))
+    }
+    #[inline(always)]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.entries.iter().map(key as fn(&(K, V)) -> &K)
+    }
+    #[inline(always)]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.entries.iter().map(value as fn(&(K, V)) -> &V)
+    }
+    #[inline(always)]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.entries.iter_mut().map(value_mut as fn(&mut (K, V)) -> &mut V)
+    }
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+}
+
+impl<K: Ord, V> SortedVectorMap<K, V> {
+    #[inline]
+    pub fn from_vector(mut entries: Vector<(K, V)>) -> Self {
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        dedup_keep_last(&mut entries);
+        SortedVectorMap { entries }
+    }
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entries.binary_search_by(|(k, _)| k.cmp(&key)) {
+            Ok(index) => Some(mem::replace(&mut self.entries[index].1, value)),
+            Err(index) => {
+                self.entries.insert(index, (key, value));
+                None
+            },
+        }
+    }
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(key).map(|index| &self.entries[index].1)
+    }
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        match self.index_of(key) {
+            Some(index) => Some(&mut self.entries[index].1),
+            None => None,
+        }
+    }
+    #[inline]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(key).and_then(|index| self.select(index))
+    }
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(key).is_some()
+    }
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+    #[inline]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        self.index_of(key).map(|index| self.entries.remove(index))
+    }
+    #[inline]
+    pub fn index_of<Q>(&self, key: &Q) -> Option<usize>
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        let index = self.rank(key);
+        match self.entries.get(index) {
+            Some((k, _)) if k.borrow() == key => Some(index),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn rank<Q>(&self, key: &Q) -> usize
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+    {
+        lower_bound(&self.entries, key, |(k, _)| k.borrow())
+    }
+    #[inline]
+    pub fn range<Q, R>(&self, range: R) -> &[(K, V)]
+        where K: Borrow<Q>,
+              Q: Ord + ?Sized,
+              R: RangeBounds<Q>,
+    {
+        let (start, end) = range_indices(&self.entries, range, |(k, _)| k.borrow());
+        &self.entries[start..end]
+    }
+}
+
+
+#[inline(always)]
+fn entry<K, V>((k, v): &(K, V)) -> (&K, &V) {
+    (k, v)
+}
+#[inline(always)]
+fn entry_mut<K, V>((k, v): &mut (K, V)) -> (&K, &mut V) {
+    (k, v)
+}
+#[inline(always)]
+fn key<K, V>((k, _): &(K, V)) -> &K {
+    k
+}
+#[inline(always)]
+fn value<K, V>((_, v): &(K, V)) -> &V {
+    v
+}
+#[inline(always)]
+fn value_mut<K, V>((_, v): &mut (K, V)) -> &mut V {
+    v
+}
+
+#[inline]
+fn dedup_keep_last<K: Ord, V>(entries: &mut Vector<(K, V)>) {
+    entries.dedup_by(|later, earlier| {
+        if later.0 == earlier.0 {
+            mem::swap(later, earlier);
+            true
+        } else {
+            false
+        }
+    });
+}
+
+
+impl<K, V> Default for SortedVectorMap<K, V> {
+    #[inline(always)]
+    fn default() -> Self {
+        SortedVectorMap::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for SortedVectorMap<K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ord + Borrow<Q>, Q: Ord + ?Sized, V> Index<&'a Q> for SortedVectorMap<K, V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &'a Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: Ord, V> From<Vector<(K, V)>> for SortedVectorMap<K, V> {
+    #[inline(always)]
+    fn from(entries: Vector<(K, V)>) -> Self {
+        SortedVectorMap::from_vector(entries)
+    }
+}
+
+impl<K, V> From<SortedVectorMap<K, V>> for Vector<(K, V)> {
+    #[inline(always)]
+    fn from(map: SortedVectorMap<K, V>) -> Self {
+        map.entries
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SortedVectorMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let mut other: Vector<(K, V)> = iter.into_iter().collect();
+        if other.is_empty() {
+            return;
+        }
+        other.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let entries = mem::take(&mut self.entries);
+        self.entries = merge_by(entries, other, |a, b| a.0.cmp(&b.0));
+        dedup_keep_last(&mut self.entries);
+    }
+}
+
+impl<'a, K: 'a + Ord + Copy, V: 'a + Copy> Extend<(&'a K, &'a V)> for SortedVectorMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(&k, &v)| (k, v)));
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SortedVectorMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        SortedVectorMap::from_vector(iter.into_iter().collect())
+    }
+}
+
+impl<K, V> IntoIterator for SortedVectorMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = vector::IntoIter<(K, V)>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SortedVectorMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut SortedVectorMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> Collection for SortedVectorMap<K, V> {
+    #[inline(always)]
+    fn len(&self) -> usize { self.entries.len() }
+}
+
+impl<K, V> CollectionMut for SortedVectorMap<K, V> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl<K: Ord, V> InsertMut<K, V> for SortedVectorMap<K, V> {
+    type Output = Option<V>;
+
+    #[inline(always)]
+    fn insert(&mut self, key: K, value: V) -> Self::Output {
+        SortedVectorMap::insert(self, key, value)
+    }
+}
+
+impl<'b, K: Ord, V> RemoveMut<&'b K> for SortedVectorMap<K, V> {
+    type Output = Option<V>;
+
+    #[inline(always)]
+    fn remove(&mut self, key: &'b K) -> Self::Output {
+        SortedVectorMap::remove(self, key)
+    }
+}
+
+impl<'a, K: 'a, V: 'a> Iterable<'a, (&'a K, &'a V)> for SortedVectorMap<K, V> {
+    type Iter = Iter<'a, K, V>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        SortedVectorMap::iter(self)
+    }
+}
+
+impl<'a, K: 'a, V: 'a> IterableMut<'a, (&'a K, &'a mut V)> for SortedVectorMap<K, V> {
+    type IterMut = IterMut<'a, K, V>;
+
+    #[inline(always)]
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        SortedVectorMap::iter_mut(self)
+    }
+}
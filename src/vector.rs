@@ -1,115 +1,462 @@
-use alloc::Bound;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
-use alloc::raw_vec::RawVec;
-use alloc::range::RangeArgument;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
 
-use core::{fmt, ptr, slice, mem};
+use core::{cmp, fmt, ptr, slice, mem};
 use core::ops::*;
-use core::ptr::Shared;
+use core::ptr::NonNull;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::num::NonZeroUsize;
 use core::hash::{self, Hash};
 use core::cmp::Ordering;
-use core::iter::{TrustedLen, FusedIterator, FromIterator};
-use core::intrinsics::{assume, arith_offset};
+use core::iter::{FusedIterator, FromIterator};
+#[cfg(feature = "nightly")]
+use core::iter::TrustedLen;
 
 use collection_traits::*;
 
+use super::allocator::{Allocator, Global};
+use super::growth_policy::{Doubling, GrowthPolicy};
+use super::raw_buffer::{handle_reserve, RawBuffer, TryReserveError};
 
-pub struct Vector<T> {
-    raw: RawVec<T>,
+
+/// `Vector<u8>` implements both `fmt::Write` and, with the `std` feature,
+/// `io::Write`, so `write!` is ambiguous when both traits are in scope; call
+/// `fmt::Write::write_fmt` or `io::Write::write_fmt` explicitly there.
+pub struct Vector<T, A: Allocator = Global, G: GrowthPolicy = Doubling> {
+    raw: RawBuffer<T, A>,
     len: usize,
+    growth: G,
 }
 
-unsafe impl<T: Send> Send for Vector<T> {}
-unsafe impl<T: Sync> Sync for Vector<T> {}
+unsafe impl<T: Send, A: Allocator + Send, G: GrowthPolicy + Send> Send for Vector<T, A, G> {}
+unsafe impl<T: Sync, A: Allocator + Sync, G: GrowthPolicy + Sync> Sync for Vector<T, A, G> {}
 
 impl<T> Vector<T> {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Vector {
-            raw: RawVec::new(),
+            raw: RawBuffer::new(),
             len: 0,
+            growth: Doubling,
         }
     }
     #[inline(always)]
     pub fn with_capacity(cap: usize) -> Self {
         Vector {
-            raw: RawVec::with_capacity(cap),
+            raw: RawBuffer::with_capacity(cap),
             len: 0,
+            growth: Doubling,
         }
     }
     #[inline(always)]
+    pub fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(cap, Global)
+    }
+    #[inline(always)]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, TryReserveError> {
+        Self::try_from_iter_in(iter, Global)
+    }
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by the global allocator for `capacity`
+    /// elements of `T`, and the first `length` elements must be initialized.
+    #[inline(always)]
     pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Vector<T> {
         Vector {
-            raw: RawVec::from_raw_parts(ptr, capacity),
+            raw: RawBuffer::from_raw_parts(ptr, capacity),
+            len: length,
+            growth: Doubling,
+        }
+    }
+    #[inline]
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        unsafe {
+            self.shrink_to_fit();
+            let raw = ptr::read(&self.raw);
+            let len = self.len;
+            mem::forget(self);
+            raw.into_box(len)
+        }
+    }
+}
+
+impl<T, A: Allocator> Vector<T, A> {
+    #[inline(always)]
+    pub const fn new_in(alloc: A) -> Self {
+        Vector {
+            raw: RawBuffer::new_in(alloc),
+            len: 0,
+            growth: Doubling,
+        }
+    }
+    #[inline(always)]
+    pub fn with_capacity_in(cap: usize, alloc: A) -> Self {
+        Vector {
+            raw: RawBuffer::with_capacity_in(cap, alloc),
+            len: 0,
+            growth: Doubling,
+        }
+    }
+    #[inline]
+    pub fn try_with_capacity_in(cap: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Ok(Vector {
+            raw: RawBuffer::try_with_capacity_in(cap, alloc)?,
+            len: 0,
+            growth: Doubling,
+        })
+    }
+    #[inline]
+    pub fn try_from_iter_in<I: IntoIterator<Item = T>>(iter: I, alloc: A) -> Result<Self, TryReserveError> {
+        let iterator = iter.into_iter();
+        let (lower, _) = iterator.size_hint();
+        let mut vector = Vector::try_with_capacity_in(lower, alloc)?;
+        vector.try_extend(iterator)?;
+        Ok(vector)
+    }
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by `alloc` for `capacity` elements of
+    /// `T`, and the first `length` elements must be initialized.
+    #[inline(always)]
+    pub unsafe fn from_raw_parts_in(ptr: *mut T, length: usize, capacity: usize, alloc: A) -> Self {
+        Vector {
+            raw: RawBuffer::from_raw_parts_in(ptr, capacity, alloc),
             len: length,
+            growth: Doubling,
+        }
+    }
+    #[inline(always)]
+    pub(crate) unsafe fn from_raw_buffer(raw: RawBuffer<T, A>, len: usize) -> Self {
+        Vector {
+            raw,
+            len,
+            growth: Doubling,
+        }
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> Vector<T, A, G> {
+    #[inline]
+    pub fn with_growth<H: GrowthPolicy>(self, growth: H) -> Vector<T, A, H> {
+        let (raw, len) = self.into_raw_buffer();
+        Vector {
+            raw,
+            len,
+            growth,
         }
     }
     #[inline(always)]
+    pub fn growth_policy(&self) -> &G {
+        &self.growth
+    }
+    #[inline(always)]
+    pub fn allocator(&self) -> &A {
+        self.raw.allocator()
+    }
+    #[inline(always)]
     pub fn capacity(&self) -> usize {
         self.raw.cap()
     }
     #[inline(always)]
     pub fn reserve(&mut self, additional: usize) {
-        self.raw.reserve(self.len, additional);
+        handle_reserve(self.try_reserve(additional))
     }
     #[inline(always)]
     pub fn reserve_exact(&mut self, additional: usize) {
         self.raw.reserve_exact(self.len, additional);
     }
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let cap = self.capacity();
+
+        if cap.wrapping_sub(self.len) >= additional {
+            return Ok(());
+        }
+
+        let required = self.len.checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        let new_cap = self.growth.grow(cap, required, mem::size_of::<T>())
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        self.raw.try_reserve_exact(self.len, cmp::max(new_cap, required) - self.len)
+    }
+    #[inline(always)]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.raw.try_reserve_exact(self.len, additional)
+    }
+    #[inline]
+    pub fn try_push(&mut self, element: T) -> Result<(), TryReserveError> {
+        self.try_reserve(1)?;
+        unsafe {
+            ptr::write(self.raw.ptr().add(self.len), element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+    #[inline]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        assert!(index <= self.len);
+        self.try_reserve(1)?;
+        self.insert(index, element);
+        Ok(())
+    }
+    #[inline]
+    pub fn try_append(&mut self, other: &mut Self) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+        self.append(other);
+        Ok(())
+    }
+    #[inline]
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let old_len = self.len;
+        let mut iterator = iter.into_iter();
+
+        while let Some(element) = iterator.next() {
+            let len = self.len;
+            if len == self.capacity() {
+                let (lower, _) = iterator.size_hint();
+                if let Err(error) = self.try_reserve(lower.saturating_add(1)) {
+                    self.truncate(old_len);
+                    return Err(error);
+                }
+            }
+            unsafe {
+                ptr::write(self.raw.ptr().add(len), element);
+                self.set_len(len + 1);
+            }
+        }
+        Ok(())
+    }
     #[inline(always)]
     pub fn shrink_to_fit(&mut self) {
         self.raw.shrink_to_fit(self.len);
     }
     #[inline]
-    pub fn into_boxed_slice(mut self) -> Box<[T]> {
-        unsafe {
-            self.shrink_to_fit();
-            let raw = ptr::read(&self.raw);
-            mem::forget(self);
-            raw.into_box()
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() > min_capacity {
+            self.raw.shrink_to_fit(cmp::max(self.len, min_capacity));
         }
     }
     #[inline(always)]
     pub fn as_slice(&self) -> &[T] {
-        &**self
+        self
     }
     #[inline(always)]
     pub fn as_slice_mut(&mut self) -> &mut [T] {
-        &mut **self
+        self
     }
+    /// # Safety
+    ///
+    /// `len` must not exceed the capacity, and the first `len` elements must
+    /// be initialized.
     #[inline(always)]
     pub unsafe fn set_len(&mut self, len: usize) {
         self.len = len;
     }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.raw.ptr()
+    }
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.raw.ptr()
+    }
     #[inline]
-    pub fn truncate(&mut self, len: usize) {
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self.split_at_spare_mut().1
+    }
+    #[inline]
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let ptr = self.raw.ptr();
+        let spare_len = self.capacity() - self.len;
+
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, self.len),
+                slice::from_raw_parts_mut(ptr.add(self.len) as *mut MaybeUninit<T>, spare_len),
+            )
+        }
+    }
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        if self.len == self.raw.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            ptr::write(self.raw.ptr().add(self.len), element);
+            self.len += 1;
+        }
+    }
+    #[inline]
+    pub fn push_within_capacity(&mut self, element: T) -> Result<(), T> {
+        if self.len == self.raw.cap() {
+            return Err(element);
+        }
         unsafe {
-            while len < self.len {
+            ptr::write(self.raw.ptr().add(self.len), element);
+            self.len += 1;
+        }
+        Ok(())
+    }
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let element = unsafe {
                 self.len -= 1;
-                let len = self.len;
-                ptr::drop_in_place(self.get_unchecked_mut(len));
+                ptr::read(self.raw.ptr().add(self.len))
+            };
+            self.shrink_after_pop();
+            Some(element)
+        }
+    }
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len;
+        assert!(index <= len, "insertion index (is {}) should be <= len (is {})", index, len);
+
+        if len == self.raw.cap() {
+            self.reserve(1);
+        }
+
+        unsafe {
+            {
+                let p = self.raw.ptr().add(index);
+                ptr::copy(p, p.add(1), len - index);
+                ptr::write(p, element);
+            }
+            self.len += 1;
+        }
+    }
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "removal index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let ret;
+            {
+                let ptr = self.raw.ptr().add(index);
+                ret = ptr::read(ptr);
+                ptr::copy(ptr.add(1), ptr, len - index - 1);
             }
+            self.len -= 1;
+            self.shrink_after_pop();
+            ret
+        }
+    }
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len, "swap_remove index (is {}) should be < len (is {})", index, len);
+
+        unsafe {
+            let ptr = self.raw.ptr();
+            let value = ptr::read(ptr.add(index));
+            ptr::copy(ptr.add(len - 1), ptr.add(index), 1);
+            self.len -= 1;
+            self.shrink_after_pop();
+            value
+        }
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.raw.ptr().add(len), self.len - len);
+            self.len = len;
+            ptr::drop_in_place(tail);
         }
+        self.shrink_after_pop();
     }
     #[inline]
     pub fn retain<F>(&mut self, mut f: F)
         where F: FnMut(&T) -> bool
+    {
+        self.retain_mut(|element| f(element))
+    }
+    #[inline]
+    pub fn retain_mut<F>(&mut self, f: F)
+        where F: FnMut(&mut T) -> bool
+    {
+        let ptr = self.raw.ptr();
+        unsafe {
+            retain_raw(ptr, &mut self.len, f);
+        }
+        self.shrink_after_pop();
+    }
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+        where F: FnMut(&mut T) -> K,
+              K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+        where F: FnMut(&mut T, &mut T) -> bool
     {
         let len = self.len;
-        let mut del = 0;
-        {
-            let v = &mut **self;
-
-            for i in 0..len {
-                if !f(&v[i]) {
-                    del += 1;
-                } else if del > 0 {
-                    v.swap(i - del, i);
+        if len <= 1 {
+            return;
+        }
+
+        struct FillGapOnDrop<'a, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy> {
+            read: usize,
+            write: usize,
+            vector: &'a mut Vector<T, A, G>,
+        }
+
+        impl<'a, T, A: Allocator, G: GrowthPolicy> Drop for FillGapOnDrop<'a, T, A, G> {
+            fn drop(&mut self) {
+                unsafe {
+                    let ptr = self.vector.raw.ptr();
+                    let len = self.vector.len;
+                    let items_left = len.wrapping_sub(self.read);
+
+                    ptr::copy(ptr.add(self.read), ptr.add(self.write), items_left);
+                    self.vector.set_len(len - (self.read - self.write));
                 }
             }
         }
-        if del > 0 {
-            self.truncate(len - del);
+
+        let mut gap = FillGapOnDrop { read: 1, write: 1, vector: self };
+        let ptr = gap.vector.raw.ptr();
+
+        unsafe {
+            while gap.read < len {
+                let read_ptr = ptr.add(gap.read);
+                let prev_ptr = ptr.add(gap.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    ptr::copy(read_ptr, ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+
+            gap.vector.set_len(gap.write);
+            mem::forget(gap);
         }
     }
     #[inline]
@@ -118,7 +465,7 @@ impl<T> Vector<T> {
 
         let len = self.len;
         unsafe {
-            ptr::copy_nonoverlapping(other.as_ptr(), self.get_unchecked_mut(len), other.len());
+            ptr::copy_nonoverlapping(other.raw.ptr(), self.raw.ptr().add(len), other.len());
         }
 
         self.len += other.len();
@@ -127,57 +474,131 @@ impl<T> Vector<T> {
         }
     }
     #[inline]
-    pub fn drain<R>(&mut self, range: R) -> Drain<T>
-        where R: RangeArgument<usize>
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, A, G>
+        where R: RangeBounds<usize>
     {
         let len = self.len();
-        let start = match range.start() {
-            Bound::Included(x) => *x,
-            Bound::Excluded(x) => *x,
-            Bound::Unbounded => 0,
-        };
-        let end = match range.end() {
-            Bound::Included(x) => *x,
-            Bound::Excluded(x) => *x,
-            Bound::Unbounded => len,
-        };
-        assert!(start <= end);
-        assert!(end <= len);
+        let (start, end) = range_bounds(&range, len);
 
         unsafe {
             self.set_len(start);
-            let range_slice = slice::from_raw_parts_mut((self.as_ptr() as *mut _).offset(start as isize),
-                                                        end - start);
+            let range_slice = slice::from_raw_parts_mut(self.raw.ptr().add(start), end - start);
             Drain {
                 tail_start: end,
                 tail_len: len - end,
                 iter: range_slice.iter(),
-                vec: Shared::new(self as *mut _),
+                vec: NonNull::from(self),
+            }
+        }
+    }
+    #[inline]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A, G>
+        where R: RangeBounds<usize>,
+              I: IntoIterator<Item = T>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+    #[inline]
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, A, G>
+        where F: FnMut(&mut T) -> bool,
+              R: RangeBounds<usize>,
+    {
+        let old_len = self.len;
+        let (start, end) = range_bounds(&range, old_len);
+
+        unsafe {
+            self.set_len(0);
+        }
+        ExtractIf {
+            vec: self,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred: filter,
+        }
+    }
+    #[inline(always)]
+    pub fn drain_filter<F>(&mut self, filter: F) -> ExtractIf<'_, T, F, A, G>
+        where F: FnMut(&mut T) -> bool
+    {
+        self.extract_if(.., filter)
+    }
+    #[inline]
+    pub fn leak<'a>(self) -> &'a mut [T]
+        where A: 'a
+    {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe {
+            slice::from_raw_parts_mut(this.as_mut_ptr(), this.len)
+        }
+    }
+    #[inline]
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let (ptr, len, cap, alloc) = self.into_raw_parts_with_alloc();
+        mem::forget(alloc);
+        (ptr, len, cap)
+    }
+    #[inline]
+    pub fn into_raw_parts_with_alloc(self) -> (*mut T, usize, usize, A) {
+        let cap = self.capacity();
+        let (raw, len) = self.into_raw_buffer();
+        let (ptr, _, alloc) = raw.into_raw_parts_with_alloc();
+        (ptr, len, cap, alloc)
+    }
+    #[inline]
+    pub(crate) fn into_raw_buffer(self) -> (RawBuffer<T, A>, usize) {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe {
+            ptr::drop_in_place(&mut this.growth);
+            (ptr::read(&this.raw), this.len)
+        }
+    }
+
+    #[inline]
+    fn shrink_after_pop(&mut self) {
+        if mem::size_of::<T>() == 0 {
+            return;
+        }
+        if let Some(new_cap) = self.growth.shrink(self.len, self.capacity()) {
+            if new_cap >= self.len && new_cap < self.capacity() {
+                self.raw.shrink_to_fit(new_cap);
             }
         }
     }
+}
+
+impl<T, A: Allocator + Clone, G: GrowthPolicy + Clone> Vector<T, A, G> {
     #[inline]
     pub fn split_off(&mut self, at: usize) -> Self {
+        handle_reserve(self.try_split_off(at))
+    }
+    #[inline]
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, TryReserveError> {
         assert!(at <= self.len(), "`at` out of bounds");
 
         let other_len = self.len - at;
-        let mut other = Vector::with_capacity(other_len);
+        let mut other = Vector::try_with_capacity_in(other_len, self.allocator().clone())?
+            .with_growth(self.growth.clone());
 
         unsafe {
             self.set_len(at);
             other.set_len(other_len);
 
             ptr::copy_nonoverlapping(
-                self.as_ptr().offset(at as isize),
-                other.as_ptr() as *mut _,
+                self.raw.ptr().add(at),
+                other.raw.ptr(),
                 other.len()
             );
         }
-        other
+        Ok(other)
     }
 }
 
-impl<T: Clone> Vector<T> {
+impl<T: Clone, A: Allocator, G: GrowthPolicy> Vector<T, A, G> {
     #[inline]
     pub fn resize(&mut self, new_len: usize, value: T) {
         let len = self.len();
@@ -189,16 +610,24 @@ impl<T: Clone> Vector<T> {
         }
     }
     #[inline]
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), TryReserveError> {
+        if new_len > self.len {
+            self.try_reserve(new_len - self.len)?;
+        }
+        self.resize(new_len, value);
+        Ok(())
+    }
+    #[inline]
     fn extend_with_element(&mut self, n: usize, value: T) {
         self.reserve(n);
 
         unsafe {
-            let mut ptr = (self.as_ptr() as *mut _).offset(self.len() as isize);
+            let mut ptr = self.raw.ptr().add(self.len());
             let mut local_len = SetLenOnDrop::new(&mut self.len);
 
             for _ in 1..n {
                 ptr::write(ptr, value.clone());
-                ptr = ptr.offset(1);
+                ptr = ptr.add(1);
                 local_len.increment_len(1);
             }
 
@@ -208,16 +637,61 @@ impl<T: Clone> Vector<T> {
             }
         }
     }
+    #[inline(always)]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        <Self as SpecExtendFromSlice<T>>::spec_extend_from_slice(self, other)
+    }
+    #[inline]
+    fn extend_from_slice_cloned(&mut self, other: &[T]) {
+        self.reserve(other.len());
+
+        unsafe {
+            let mut ptr = self.raw.ptr().add(self.len());
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+
+            for value in other {
+                ptr::write(ptr, value.clone());
+                ptr = ptr.add(1);
+                local_len.increment_len(1);
+            }
+        }
+    }
+    #[inline]
+    pub fn extend_from_within<R>(&mut self, src: R)
+        where R: RangeBounds<usize>
+    {
+        let (start, end) = range_bounds(&src, self.len);
+        self.reserve(end - start);
+
+        unsafe {
+            let ptr = self.raw.ptr();
+            let mut dst = ptr.add(self.len);
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+
+            for i in start..end {
+                ptr::write(dst, (*ptr.add(i)).clone());
+                dst = dst.add(1);
+                local_len.increment_len(1);
+            }
+        }
+    }
+}
+
+impl<T: PartialEq, A: Allocator, G: GrowthPolicy> Vector<T, A, G> {
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
+    }
 }
 
-impl<T> Default for Vector<T> {
+impl<T, A: Allocator + Default, G: GrowthPolicy + Default> Default for Vector<T, A, G> {
     #[inline(always)]
     fn default() -> Self {
-        Self::new()
+        Vector::new_in(A::default()).with_growth(G::default())
     }
 }
 
-impl<T> Drop for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Drop for Vector<T, A, G> {
     #[inline(always)]
     fn drop(&mut self) {
         unsafe {
@@ -227,64 +701,45 @@ impl<T> Drop for Vector<T> {
 }
 
 macro_rules! __impl_slice_eq1 {
-    ($Lhs: ty, $Rhs: ty) => {
-        __impl_slice_eq1! { $Lhs, $Rhs, Sized }
-    };
-    ($Lhs: ty, $Rhs: ty, $Bound: ident) => {
-        impl<'a, 'b, A: $Bound, B> PartialEq<$Rhs> for $Lhs where A: PartialEq<B> {
+    ([$($vars:tt)*] $Lhs: ty, $Rhs: ty) => {
+        impl<'b, T, U, A: Allocator, G: GrowthPolicy, $($vars)*> PartialEq<$Rhs> for $Lhs where T: PartialEq<U> {
             #[inline(always)]
             fn eq(&self, other: &$Rhs) -> bool { self[..] == other[..] }
-            #[inline(always)]
-            fn ne(&self, other: &$Rhs) -> bool { self[..] != other[..] }
         }
     }
 }
 
-__impl_slice_eq1! { Vector<A>, Vector<B> }
-__impl_slice_eq1! { Vector<A>, &'b [B] }
-__impl_slice_eq1! { Vector<A>, &'b mut [B] }
-
-macro_rules! array_impls {
-    ($($N: expr)+) => {
-        $(
-            __impl_slice_eq1! { Vector<A>, [B; $N] }
-            __impl_slice_eq1! { Vector<A>, &'b [B; $N] }
-            __impl_slice_eq1! { Vector<A>, &'b mut [B; $N] }
-        )+
-    }
-}
-
-array_impls! {
-     0  1  2  3  4  5  6  7  8  9
-    10 11 12 13 14 15 16 17 18 19
-    20 21 22 23 24 25 26 27 28 29
-    30 31 32
-}
+__impl_slice_eq1! { [B: Allocator, H: GrowthPolicy] Vector<T, A, G>, Vector<U, B, H> }
+__impl_slice_eq1! { [] Vector<T, A, G>, &'b [U] }
+__impl_slice_eq1! { [] Vector<T, A, G>, &'b mut [U] }
+__impl_slice_eq1! { [const N: usize] Vector<T, A, G>, [U; N] }
+__impl_slice_eq1! { [const N: usize] Vector<T, A, G>, &'b [U; N] }
+__impl_slice_eq1! { [const N: usize] Vector<T, A, G>, &'b mut [U; N] }
 
-impl<T: PartialOrd> PartialOrd for Vector<T> {
+impl<T: PartialOrd, A: Allocator, G: GrowthPolicy> PartialOrd for Vector<T, A, G> {
     #[inline]
-    fn partial_cmp(&self, other: &Vector<T>) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         PartialOrd::partial_cmp(&**self, &**other)
     }
 }
 
-impl<T: Eq> Eq for Vector<T> {}
+impl<T: Eq, A: Allocator, G: GrowthPolicy> Eq for Vector<T, A, G> {}
 
-impl<T: Ord> Ord for Vector<T> {
+impl<T: Ord, A: Allocator, G: GrowthPolicy> Ord for Vector<T, A, G> {
     #[inline]
-    fn cmp(&self, other: &Vector<T>) -> Ordering {
+    fn cmp(&self, other: &Self) -> Ordering {
         Ord::cmp(&**self, &**other)
     }
 }
 
-impl<T: Hash> Hash for Vector<T> {
+impl<T: Hash, A: Allocator, G: GrowthPolicy> Hash for Vector<T, A, G> {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         Hash::hash(&**self, state)
     }
 }
 
-impl<T> Deref for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Deref for Vector<T, A, G> {
     type Target = [T];
 
     #[inline(always)]
@@ -294,7 +749,7 @@ impl<T> Deref for Vector<T> {
         }
     }
 }
-impl<T> DerefMut for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> DerefMut for Vector<T, A, G> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe {
@@ -303,30 +758,75 @@ impl<T> DerefMut for Vector<T> {
     }
 }
 
-impl<T: fmt::Debug> fmt::Debug for Vector<T> {
+impl<T: fmt::Debug, A: Allocator, G: GrowthPolicy> fmt::Debug for Vector<T, A, G> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(&**self, f)
     }
 }
 
-impl<T: Clone> Clone for Vector<T> {
+impl<T: Clone, A: Allocator + Clone, G: GrowthPolicy + Clone> Vector<T, A, G> {
+    #[inline]
+    pub fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let mut vector = Vector::try_with_capacity_in(self.len, self.allocator().clone())?
+            .with_growth(self.growth.clone());
+        vector.extend_from_slice(self);
+        Ok(vector)
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone, G: GrowthPolicy + Clone> Clone for Vector<T, A, G> {
     #[inline]
     fn clone(&self) -> Self {
-        let cap = self.raw.cap();
-        let new_raw = RawVec::with_capacity(cap);
+        let mut vector = Vector::with_capacity_in(self.len, self.allocator().clone())
+            .with_growth(self.growth.clone());
+        vector.extend_from_slice(self);
+        vector
+    }
+    #[inline]
+    fn clone_from(&mut self, other: &Self) {
+        self.truncate(other.len);
 
-        unsafe {
-            ptr::copy_nonoverlapping(self.raw.ptr(), new_raw.ptr(), cap);
-        }
+        let (init, tail) = other.split_at(self.len);
+        self.clone_from_slice(init);
+        self.extend_from_slice(tail);
+    }
+}
 
-        Vector {
-            raw: new_raw,
-            len: self.len,
+trait SpecExtendFromSlice<T> {
+    fn spec_extend_from_slice(&mut self, other: &[T]);
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T: Clone, A: Allocator, G: GrowthPolicy> SpecExtendFromSlice<T> for Vector<T, A, G> {
+    #[inline(always)]
+    fn spec_extend_from_slice(&mut self, other: &[T]) {
+        self.extend_from_slice_cloned(other)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, A: Allocator, G: GrowthPolicy> SpecExtendFromSlice<T> for Vector<T, A, G> {
+    #[inline(always)]
+    default fn spec_extend_from_slice(&mut self, other: &[T]) {
+        self.extend_from_slice_cloned(other)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Copy, A: Allocator, G: GrowthPolicy> SpecExtendFromSlice<T> for Vector<T, A, G> {
+    #[inline]
+    fn spec_extend_from_slice(&mut self, other: &[T]) {
+        let count = other.len();
+        self.reserve(count);
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.raw.ptr().add(self.len), count);
+            self.len += count;
         }
     }
 }
 
-impl<T> Index<usize> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<usize> for Vector<T, A, G> {
     type Output = T;
 
     #[inline(always)]
@@ -334,14 +834,14 @@ impl<T> Index<usize> for Vector<T> {
         &(**self)[index]
     }
 }
-impl<T> IndexMut<usize> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<usize> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut (**self)[index]
     }
 }
 
-impl<T> Index<Range<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<Range<usize>> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -349,7 +849,7 @@ impl<T> Index<Range<usize>> for Vector<T> {
         Index::index(&**self, index)
     }
 }
-impl<T> Index<RangeTo<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<RangeTo<usize>> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -357,7 +857,7 @@ impl<T> Index<RangeTo<usize>> for Vector<T> {
         Index::index(&**self, index)
     }
 }
-impl<T> Index<RangeFrom<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<RangeFrom<usize>> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -365,7 +865,7 @@ impl<T> Index<RangeFrom<usize>> for Vector<T> {
         Index::index(&**self, index)
     }
 }
-impl<T> Index<RangeFull> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<RangeFull> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -373,7 +873,7 @@ impl<T> Index<RangeFull> for Vector<T> {
         self
     }
 }
-impl<T> Index<RangeInclusive<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<RangeInclusive<usize>> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -381,7 +881,7 @@ impl<T> Index<RangeInclusive<usize>> for Vector<T> {
         Index::index(&**self, index)
     }
 }
-impl<T> Index<RangeToInclusive<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Index<RangeToInclusive<usize>> for Vector<T, A, G> {
     type Output = [T];
 
     #[inline(always)]
@@ -390,195 +890,126 @@ impl<T> Index<RangeToInclusive<usize>> for Vector<T> {
     }
 }
 
-impl<T> IndexMut<Range<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<Range<usize>> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: Range<usize>) -> &mut [T] {
         IndexMut::index_mut(&mut **self, index)
     }
 }
-impl<T> IndexMut<RangeTo<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<RangeTo<usize>> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: RangeTo<usize>) -> &mut [T] {
         IndexMut::index_mut(&mut **self, index)
     }
 }
-impl<T> IndexMut<RangeFrom<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<RangeFrom<usize>> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: RangeFrom<usize>) -> &mut [T] {
         IndexMut::index_mut(&mut **self, index)
     }
 }
-impl<T> IndexMut<RangeFull> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<RangeFull> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, _index: RangeFull) -> &mut [T] {
         self
     }
 }
-impl<T> IndexMut<RangeInclusive<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<RangeInclusive<usize>> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut [T] {
         IndexMut::index_mut(&mut **self, index)
     }
 }
-impl<T> IndexMut<RangeToInclusive<usize>> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> IndexMut<RangeToInclusive<usize>> for Vector<T, A, G> {
     #[inline(always)]
     fn index_mut(&mut self, index: RangeToInclusive<usize>) -> &mut [T] {
         IndexMut::index_mut(&mut **self, index)
     }
 }
 
-impl<T> Collection for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Collection for Vector<T, A, G> {
     #[inline(always)]
     fn len(&self) -> usize { self.len }
 }
 
-impl<T> CollectionMut for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> CollectionMut for Vector<T, A, G> {
     #[inline(always)]
     fn clear(&mut self) {
-        self.truncate(0)
+        Vector::clear(self)
     }
 }
 
-impl<T> InsertMut<usize, T> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> InsertMut<usize, T> for Vector<T, A, G> {
     type Output = ();
 
-    #[inline]
+    #[inline(always)]
     fn insert(&mut self, index: usize, element: T) -> Self::Output {
-        let len = self.len;
-        assert!(index <= len);
-
-        if len == self.raw.cap() {
-            self.raw.double();
-        }
-
-        unsafe {
-            {
-                let p = (self.as_ptr() as *mut _).offset(index as isize);
-                ptr::copy(p, p.offset(1), len - index);
-                ptr::write(p, element);
-            }
-            self.len += 1;
-        }
+        Vector::insert(self, index, element)
     }
 }
 
-impl<T> RemoveMut<usize> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> RemoveMut<usize> for Vector<T, A, G> {
     type Output = T;
 
-    #[inline]
+    #[inline(always)]
     fn remove(&mut self, index: usize) -> T {
-        let len = self.len;
-        assert!(index < len);
-        unsafe {
-            let ret;
-            {
-                let ptr = (self.as_ptr() as *mut _).offset(index as isize);
-                ret = ptr::read(ptr);
-                ptr::copy(ptr.offset(1), ptr, len - index - 1);
-            }
-            self.len -= 1;
-            ret
-        }
+        Vector::remove(self, index)
     }
 }
 
-impl<T> DequeMut<T> for Vector<T> {
-    #[inline]
+/// `push_front` and `pop_front` shift every element and are O(n); use
+/// `VectorDeque` when O(1) operations at the front are needed.
+impl<T, A: Allocator, G: GrowthPolicy> DequeMut<T> for Vector<T, A, G> {
+    #[inline(always)]
     fn push_front(&mut self, element: T) {
-        if self.len == self.raw.cap() {
-            self.raw.double();
-        }
-        unsafe {
-            let end = (self.as_ptr() as *mut _).offset(self.len as isize);
-            ptr::write(end, element);
-            self.len += 1;
-        }
+        self.insert(0, element);
     }
     #[inline(always)]
     fn push_back(&mut self, element: T) {
-        self.insert(0, element);
+        Vector::push(self, element)
     }
-    #[inline]
+    #[inline(always)]
     fn pop_front(&mut self) -> Option<T> {
         if self.len == 0 {
             None
         } else {
-            unsafe {
-                self.len -= 1;
-                Some(ptr::read(self.get_unchecked(self.len)))
-            }
+            Some(self.remove(0))
         }
     }
     #[inline(always)]
     fn pop_back(&mut self) -> Option<T> {
-        if self.len == 0 {
-            None
-        } else {
-            Some(self.remove(0))
-        }
+        Vector::pop(self)
     }
-    #[inline]
+    #[inline(always)]
     fn front(&self) -> Option<&T> {
-        let len = self.len;
-
-        if len == 0 {
-            None
-        } else {
-            unsafe {
-                Some(self.get_unchecked(len - 1))
-            }
-        }
+        self.first()
     }
-    #[inline]
+    #[inline(always)]
     fn back(&self) -> Option<&T> {
-        let len = self.len;
-
-        if len == 0 {
-            None
-        } else {
-            unsafe {
-                Some(self.get_unchecked(0))
-            }
-        }
+        self.last()
     }
-    #[inline]
+    #[inline(always)]
     fn front_mut(&mut self) -> Option<&mut T> {
-        let len = self.len;
-
-        if len == 0 {
-            None
-        } else {
-            unsafe {
-                Some(self.get_unchecked_mut(len - 1))
-            }
-        }
+        self.first_mut()
     }
-    #[inline]
+    #[inline(always)]
     fn back_mut(&mut self) -> Option<&mut T> {
-        let len = self.len;
-
-        if len == 0 {
-            None
-        } else {
-            unsafe {
-                Some(self.get_unchecked_mut(0))
-            }
-        }
+        self.last_mut()
     }
 }
 
-impl<T> StackMut<T> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> StackMut<T> for Vector<T, A, G> {
     #[inline(always)]
-    fn push(&mut self, element: T) { self.push_front(element) }
+    fn push(&mut self, element: T) { self.push_back(element) }
     #[inline(always)]
-    fn pop(&mut self) -> Option<T> { self.pop_front() }
+    fn pop(&mut self) -> Option<T> { self.pop_back() }
     #[inline(always)]
-    fn top(&self) -> Option<&T> { self.front() }
+    fn top(&self) -> Option<&T> { self.back() }
     #[inline(always)]
-    fn top_mut(&mut self) -> Option<&mut T> { self.front_mut() }
+    fn top_mut(&mut self) -> Option<&mut T> { self.back_mut() }
 }
 
-impl<T> QueueMut<T> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> QueueMut<T> for Vector<T, A, G> {
     #[inline(always)]
     fn enqueue(&mut self, element: T) { self.push_back(element) }
     #[inline(always)]
@@ -589,61 +1020,75 @@ impl<T> QueueMut<T> for Vector<T> {
     fn peek_mut(&mut self) -> Option<&mut T> { self.front_mut() }
 }
 
-impl<'a, T: 'a> Iterable<'a, &'a T> for Vector<T> {
+impl<'a, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy> Iterable<'a, &'a T> for Vector<T, A, G> {
     type Iter = slice::Iter<'a, T>;
 
     #[inline(always)]
     fn iter(&'a self) -> Self::Iter {
-        (&**self).iter()
+        (**self).iter()
     }
 }
 
-impl<'a, T: 'a> IterableMut<'a, &'a mut T> for Vector<T> {
+impl<'a, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy> IterableMut<'a, &'a mut T> for Vector<T, A, G> {
     type IterMut = slice::IterMut<'a, T>;
 
     #[inline(always)]
     fn iter_mut(&'a mut self) -> Self::IterMut {
-        (&mut **self).iter_mut()
+        (**self).iter_mut()
     }
 }
 
-impl<'a, T: 'a> SeqMut<'a, T> for Vector<T> {}
+impl<'a, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy> SeqMut<'a, T> for Vector<T, A, G> {}
 
 
 impl<T> FromIterator<T> for Vector<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T> {
-        <Self as SpecExtend<_, _>>::from_iter(iter.into_iter())
+        <Self as SpecFromIter<_, _>>::from_iter(iter.into_iter())
     }
 }
 
-impl<T> IntoIterator for Vector<T> {
+pub(crate) trait SpecFromIter<T, I> {
+    fn from_iter(iter: I) -> Self;
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, I: Iterator<Item = T>> SpecFromIter<T, I> for Vector<T> {
+    #[inline(always)]
+    fn from_iter(iter: I) -> Self {
+        <Self as SpecExtend<_, _, _>>::from_iter_in(iter, Global)
+    }
+}
+
+impl<T, A: Allocator, G: GrowthPolicy> IntoIterator for Vector<T, A, G> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, A>;
 
     #[inline]
-    fn into_iter(self) -> IntoIter<T> {
+    fn into_iter(self) -> IntoIter<T, A> {
+        let len = self.len;
+        let (raw, _) = self.into_raw_buffer();
+        let (begin, cap, alloc) = raw.into_raw_parts_with_alloc();
+
         unsafe {
-            let begin = self.as_ptr() as *mut _;
-            assume(!begin.is_null());
             let end = if mem::size_of::<T>() == 0 {
-                arith_offset(begin as *const i8, self.len() as isize) as *const T
+                (begin as *const T).wrapping_byte_add(len)
             } else {
-                begin.offset(self.len() as isize) as *const T
+                begin.add(len) as *const T
             };
-            let cap = self.raw.cap();
-            mem::forget(self);
             IntoIter {
-                raw: Shared::new(begin),
-                cap: cap,
+                raw: NonNull::new_unchecked(begin),
+                cap,
+                alloc: mem::ManuallyDrop::new(alloc),
                 ptr: begin,
-                end: end,
+                end,
+                marker: PhantomData,
             }
         }
     }
 }
 
-impl<'a, T> IntoIterator for &'a Vector<T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> IntoIterator for &'a Vector<T, A, G> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
 
@@ -652,38 +1097,38 @@ impl<'a, T> IntoIterator for &'a Vector<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut Vector<T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> IntoIterator for &'a mut Vector<T, A, G> {
     type Item = &'a mut T;
     type IntoIter = slice::IterMut<'a, T>;
 
-    fn into_iter(mut self) -> slice::IterMut<'a, T> {
+    fn into_iter(self) -> slice::IterMut<'a, T> {
         self.iter_mut()
     }
 }
 
-impl<T> Extend<T> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Extend<T> for Vector<T, A, G> {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.spec_extend(iter.into_iter())
+        self.extend_desugared(iter.into_iter())
     }
 }
 
-trait SpecExtend<T, I> {
-    fn from_iter(iter: I) -> Self;
+pub(crate) trait SpecExtend<T, I, A> {
+    fn from_iter_in(iter: I, alloc: A) -> Self;
     fn spec_extend(&mut self, iter: I);
 }
 
-impl<T, I> SpecExtend<T, I> for Vector<T>
+impl<T, I, A: Allocator> SpecExtend<T, I, A> for Vector<T, A>
     where I: Iterator<Item=T>,
 {
-    default fn from_iter(mut iterator: I) -> Self {
+    fn from_iter_in(mut iterator: I, alloc: A) -> Self {
         let mut vector = match iterator.next() {
-            None => return Vector::new(),
+            None => return Vector::new_in(alloc),
             Some(element) => {
                 let (lower, _) = iterator.size_hint();
-                let mut vector = Vector::with_capacity(lower.saturating_add(1));
+                let mut vector = Vector::with_capacity_in(lower.saturating_add(1), alloc);
                 unsafe {
-                    ptr::write(vector.get_unchecked_mut(0), element);
+                    ptr::write(vector.raw.ptr(), element);
                     vector.set_len(1);
                 }
                 vector
@@ -693,7 +1138,7 @@ impl<T, I> SpecExtend<T, I> for Vector<T>
         vector
     }
 
-    default fn spec_extend(&mut self, iter: I) {
+    fn spec_extend(&mut self, iter: I) {
         self.extend_desugared(iter)
     }
 }
@@ -706,7 +1151,7 @@ struct SetLenOnDrop<'a> {
 impl<'a> SetLenOnDrop<'a> {
     #[inline]
     fn new(len: &'a mut usize) -> Self {
-        SetLenOnDrop { local_len: *len, len: len }
+        SetLenOnDrop { local_len: *len, len }
     }
     #[inline]
     fn increment_len(&mut self, increment: usize) {
@@ -721,67 +1166,7 @@ impl<'a> Drop for SetLenOnDrop<'a> {
     }
 }
 
-impl<T, I> SpecExtend<T, I> for Vector<T>
-    where I: TrustedLen<Item=T>,
-{
-    fn from_iter(iterator: I) -> Self {
-        let mut vector = Vector::new();
-        vector.spec_extend(iterator);
-        vector
-    }
-
-    fn spec_extend(&mut self, iterator: I) {
-        let (low, high) = iterator.size_hint();
-        if let Some(high_value) = high {
-            debug_assert_eq!(low, high_value,
-                             "TrustedLen iterator's size hint is not exact: {:?}",
-                             (low, high));
-        }
-        if let Some(additional) = high {
-            self.reserve(additional);
-            unsafe {
-                let mut ptr = (self.as_ptr() as *mut _).offset(self.len() as isize);
-                let mut local_len = SetLenOnDrop::new(&mut self.len);
-                for element in iterator {
-                    ptr::write(ptr, element);
-                    ptr = ptr.offset(1);
-                    local_len.increment_len(1);
-                }
-            }
-        } else {
-            self.extend_desugared(iterator)
-        }
-    }
-}
-
-impl<'a, T: 'a, I> SpecExtend<&'a T, I> for Vector<T>
-    where I: Iterator<Item=&'a T>,
-          T: Clone,
-{
-    default fn from_iter(iterator: I) -> Self {
-        SpecExtend::from_iter(iterator.cloned())
-    }
-
-    default fn spec_extend(&mut self, iterator: I) {
-        self.spec_extend(iterator.cloned())
-    }
-}
-
-impl<'a, T: 'a> SpecExtend<&'a T, slice::Iter<'a, T>> for Vector<T>
-    where T: Copy,
-{
-    fn spec_extend(&mut self, iterator: slice::Iter<'a, T>) {
-        let slice = iterator.as_slice();
-        self.reserve(slice.len());
-        unsafe {
-            let len = self.len();
-            self.set_len(len + slice.len());
-            self.get_unchecked_mut(len..).copy_from_slice(slice);
-        }
-    }
-}
-
-impl<T> Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> Vector<T, A, G> {
     fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iterator: I) {
         while let Some(element) = iterator.next() {
             let len = self.len();
@@ -790,72 +1175,162 @@ impl<T> Vector<T> {
                 self.reserve(lower.saturating_add(1));
             }
             unsafe {
-                ptr::write(self.get_unchecked_mut(len), element);
+                ptr::write(self.raw.ptr().add(len), element);
                 self.set_len(len + 1);
             }
         }
     }
 }
 
-impl<'a, T: 'a + Copy> Extend<&'a T> for Vector<T> {
+impl<'a, T: 'a + Copy, A: Allocator, G: GrowthPolicy> Extend<&'a T> for Vector<T, A, G> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        self.spec_extend(iter.into_iter())
+        self.extend_desugared(iter.into_iter().cloned())
     }
 }
 
-impl<T> AsRef<Vector<T>> for Vector<T> {
-    fn as_ref(&self) -> &Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsRef<Vector<T, A, G>> for Vector<T, A, G> {
+    fn as_ref(&self) -> &Vector<T, A, G> {
         self
     }
 }
 
-impl<T> AsMut<Vector<T>> for Vector<T> {
-    fn as_mut(&mut self) -> &mut Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsMut<Vector<T, A, G>> for Vector<T, A, G> {
+    fn as_mut(&mut self) -> &mut Vector<T, A, G> {
         self
     }
 }
 
-impl<T> AsRef<[T]> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsRef<[T]> for Vector<T, A, G> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
 
-impl<T> AsMut<[T]> for Vector<T> {
+impl<T, A: Allocator, G: GrowthPolicy> AsMut<[T]> for Vector<T, A, G> {
     fn as_mut(&mut self) -> &mut [T] {
         self
     }
 }
 
 impl<'a, T: Clone> From<&'a [T]> for Vector<T> {
+    #[inline]
     fn from(s: &'a [T]) -> Vector<T> {
+        let mut vector = Vector::with_capacity(s.len());
+        vector.extend_from_slice(s);
+        vector
+    }
+}
+
+impl<'a, T: Clone> From<&'a mut [T]> for Vector<T> {
+    #[inline]
+    fn from(s: &'a mut [T]) -> Vector<T> {
+        From::from(&*s)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T> {
+    #[inline]
+    fn from(array: [T; N]) -> Vector<T> {
+        let array = mem::ManuallyDrop::new(array);
+        let mut vector = Vector::with_capacity(N);
+
         unsafe {
-            let len = s.len();
-            let raw = RawVec::with_capacity(len.next_power_of_two());
-            ptr::copy_nonoverlapping(s.as_ptr(), raw.ptr(), len);
+            ptr::copy_nonoverlapping(array.as_ptr(), vector.raw.ptr(), N);
+            vector.set_len(N);
+        }
+        vector
+    }
+}
 
-            Vector {
-                raw: raw,
-                len: len,
-            }
+impl<T> From<Box<[T]>> for Vector<T> {
+    #[inline]
+    fn from(slice: Box<[T]>) -> Vector<T> {
+        let len = slice.len();
+
+        unsafe {
+            Vector::from_raw_parts(Box::into_raw(slice) as *mut T, len, len)
+        }
+    }
+}
+
+impl<T> From<Vector<T>> for Box<[T]> {
+    #[inline(always)]
+    fn from(vector: Vector<T>) -> Box<[T]> {
+        vector.into_boxed_slice()
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Vector<T> {
+        let mut vec = mem::ManuallyDrop::new(vec);
+
+        unsafe {
+            Vector::from_raw_parts(vec.as_mut_ptr(), vec.len(), vec.capacity())
+        }
+    }
+}
+
+impl<T> From<Vector<T>> for Vec<T> {
+    #[inline]
+    fn from(vector: Vector<T>) -> Vec<T> {
+        let vector = mem::ManuallyDrop::new(vector);
+
+        unsafe {
+            Vec::from_raw_parts(vector.raw.ptr(), vector.len, vector.raw.cap())
+        }
+    }
+}
+
+impl<T> From<VecDeque<T>> for Vector<T> {
+    #[inline(always)]
+    fn from(deque: VecDeque<T>) -> Vector<T> {
+        From::from(Vec::from(deque))
+    }
+}
+
+impl<'a, T: Clone> From<Cow<'a, [T]>> for Vector<T> {
+    #[inline]
+    fn from(cow: Cow<'a, [T]>) -> Vector<T> {
+        match cow {
+            Cow::Borrowed(s) => From::from(s),
+            Cow::Owned(vec) => From::from(vec),
         }
     }
 }
 
 impl<'a> From<&'a str> for Vector<u8> {
+    #[inline(always)]
     fn from(s: &'a str) -> Vector<u8> {
         From::from(s.as_bytes())
     }
 }
 
-pub struct IntoIter<T> {
-    raw: Shared<T>,
+impl From<String> for Vector<u8> {
+    #[inline(always)]
+    fn from(s: String) -> Vector<u8> {
+        From::from(s.into_bytes())
+    }
+}
+
+impl<A: Allocator, G: GrowthPolicy> fmt::Write for Vector<u8, A, G> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+pub struct IntoIter<T, A: Allocator = Global> {
+    raw: NonNull<T>,
     cap: usize,
+    alloc: mem::ManuallyDrop<A>,
     ptr: *const T,
     end: *const T,
+    marker: PhantomData<T>,
 }
 
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("IntoIter")
             .field(&self.as_slice())
@@ -863,122 +1338,191 @@ impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
     }
 }
 
-impl<T> IntoIter<T> {
+impl<T, A: Allocator> IntoIter<T, A> {
+    #[inline(always)]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
     pub fn as_slice(&self) -> &[T] {
         unsafe {
-            slice::from_raw_parts(self.ptr, self.len())
+            slice::from_raw_parts(self.slice_ptr(), self.len())
         }
     }
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         unsafe {
-            slice::from_raw_parts_mut(self.ptr as *mut T, self.len())
+            slice::from_raw_parts_mut(self.slice_ptr(), self.len())
+        }
+    }
+    pub fn into_vector(self) -> Vector<T, A> {
+        let mut this = mem::ManuallyDrop::new(self);
+        let len = this.len();
+
+        unsafe {
+            if mem::size_of::<T>() != 0 && !ptr::eq(this.ptr, this.raw.as_ptr()) {
+                ptr::copy(this.ptr, this.raw.as_ptr(), len);
+            }
+            let alloc = mem::ManuallyDrop::take(&mut this.alloc);
+            Vector::from_raw_parts_in(this.raw.as_ptr(), len, this.cap, alloc)
+        }
+    }
+    pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let step = cmp::min(n, self.len());
+
+        if mem::size_of::<T>() == 0 {
+            self.ptr = self.ptr.wrapping_byte_add(step);
+        } else {
+            let to_drop = ptr::slice_from_raw_parts_mut(self.ptr as *mut T, step);
+            unsafe {
+                self.ptr = self.ptr.add(step);
+                ptr::drop_in_place(to_drop);
+            }
+        }
+        NonZeroUsize::new(n - step).map_or(Ok(()), Err)
+    }
+    #[inline(always)]
+    pub fn forget_remaining_elements(&mut self) {
+        self.ptr = self.end;
+    }
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    pub(crate) fn buffer(&self) -> (*mut T, usize) {
+        (self.raw.as_ptr(), self.cap)
+    }
+    #[cfg(feature = "nightly")]
+    pub(crate) fn forget_allocation_drop_remaining(&mut self) {
+        let remaining = self.as_mut_slice() as *mut [T];
+
+        self.raw = NonNull::dangling();
+        self.cap = 0;
+        self.ptr = self.raw.as_ptr();
+        self.end = self.raw.as_ptr();
+
+        unsafe {
+            ptr::drop_in_place(remaining);
+        }
+    }
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        if mem::size_of::<T>() == 0 {
+            self.end.addr().wrapping_sub(self.ptr.addr())
+        } else {
+            unsafe { self.end.offset_from(self.ptr) as usize }
+        }
+    }
+    #[inline(always)]
+    fn slice_ptr(&self) -> *mut T {
+        if mem::size_of::<T>() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.ptr as *mut T
         }
     }
 }
 
-unsafe impl<T: Send> Send for IntoIter<T> {}
-unsafe impl<T: Sync> Sync for IntoIter<T> {}
+unsafe impl<T: Send, A: Allocator + Send> Send for IntoIter<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for IntoIter<T, A> {}
 
-impl<T> Iterator for IntoIter<T> {
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     #[inline]
     fn next(&mut self) -> Option<T> {
         unsafe {
-            if self.ptr as *const _ == self.end {
+            if self.ptr == self.end {
                 None
+            } else if mem::size_of::<T>() == 0 {
+                self.ptr = self.ptr.wrapping_byte_add(1);
+                Some(ptr::read(NonNull::dangling().as_ptr()))
             } else {
-                if mem::size_of::<T>() == 0 {
-                    self.ptr = arith_offset(self.ptr as *const i8, 1) as *mut T;
-                    Some(ptr::read(Shared::empty().as_ptr()))
-                } else {
-                    let old = self.ptr;
-                    self.ptr = self.ptr.offset(1);
+                let old = self.ptr;
+                self.ptr = self.ptr.add(1);
 
-                    Some(ptr::read(old))
-                }
+                Some(ptr::read(old))
             }
         }
     }
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let diff = (self.end as usize) - (self.ptr as usize);
-        let size = mem::size_of::<T>();
-        let exact = diff /
-                    (if size == 0 {
-                         1
-                     } else {
-                         size
-                     });
+        let exact = self.remaining();
         (exact, Some(exact))
     }
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.advance_by(n).ok()?;
+        self.next()
+    }
     #[inline(always)]
     fn count(self) -> usize {
         self.len()
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T> {
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
     #[inline]
     fn next_back(&mut self) -> Option<T> {
         unsafe {
             if self.end == self.ptr {
                 None
+            } else if mem::size_of::<T>() == 0 {
+                self.end = self.end.wrapping_byte_sub(1);
+                Some(ptr::read(NonNull::dangling().as_ptr()))
             } else {
-                if mem::size_of::<T>() == 0 {
-                    self.end = arith_offset(self.end as *const i8, -1) as *mut T;
-                    Some(ptr::read(Shared::empty().as_ptr() as *mut T))
-                } else {
-                    self.end = self.end.offset(-1);
+                self.end = self.end.sub(1);
 
-                    Some(ptr::read(self.end))
-                }
+                Some(ptr::read(self.end))
             }
         }
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T> {
-    fn is_empty(&self) -> bool {
-        self.ptr == self.end
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.remaining()
     }
 }
 
-impl<T> FusedIterator for IntoIter<T> {}
-
-unsafe impl<T> TrustedLen for IntoIter<T> {}
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
 
-impl<T: Clone> Clone for IntoIter<T> {
-    fn clone(&self) -> IntoIter<T> {
-        IntoIter {
-            raw: self.raw,
-            cap: self.cap,
-            ptr: self.ptr,
-            end: self.end,
-        }
+impl<T: Clone, A: Allocator + Clone> Clone for IntoIter<T, A> {
+    fn clone(&self) -> IntoIter<T, A> {
+        let mut vector = Vector::with_capacity_in(self.len(), (*self.alloc).clone());
+        vector.extend_from_slice(self.as_slice());
+        vector.into_iter()
     }
 }
 
-impl<T> Drop for IntoIter<T> {
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
     fn drop(&mut self) {
-        for _x in self.by_ref() {}
-        let _ = unsafe {
-            RawVec::from_raw_parts(self.raw.as_ptr(), self.cap)
-        };
+        struct DropGuard<'a, T: 'a, A: 'a + Allocator>(&'a mut IntoIter<T, A>);
+
+        impl<'a, T, A: Allocator> Drop for DropGuard<'a, T, A> {
+            fn drop(&mut self) {
+                let _ = unsafe {
+                    let alloc = mem::ManuallyDrop::take(&mut self.0.alloc);
+                    RawBuffer::from_raw_parts_in(self.0.raw.as_ptr(), self.0.cap, alloc)
+                };
+            }
+        }
+
+        let guard = DropGuard(self);
+        unsafe {
+            ptr::drop_in_place(guard.0.as_mut_slice());
+        }
     }
 }
 
-pub struct Drain<'a, T: 'a> {
+pub struct Drain<'a, T: 'a, A: 'a + Allocator = Global, G: 'a + GrowthPolicy = Doubling> {
     tail_start: usize,
     tail_len: usize,
     iter: slice::Iter<'a, T>,
-    vec: Shared<Vector<T>>,
+    vec: NonNull<Vector<T, A, G>>,
 }
 
-unsafe impl<'a, T: Sync> Sync for Drain<'a, T> {}
-unsafe impl<'a, T: Send> Send for Drain<'a, T> {}
+unsafe impl<'a, T: Sync, A: Allocator + Sync, G: GrowthPolicy + Sync> Sync for Drain<'a, T, A, G> {}
+unsafe impl<'a, T: Send, A: Allocator + Send, G: GrowthPolicy + Send> Send for Drain<'a, T, A, G> {}
 
-impl<'a, T> Iterator for Drain<'a, T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> Iterator for Drain<'a, T, A, G> {
     type Item = T;
 
     #[inline]
@@ -991,43 +1535,347 @@ impl<'a, T> Iterator for Drain<'a, T> {
     }
 }
 
-impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> DoubleEndedIterator for Drain<'a, T, A, G> {
     #[inline]
     fn next_back(&mut self) -> Option<T> {
         self.iter.next_back().map(|elt| unsafe { ptr::read(elt as *const _) })
     }
 }
 
-impl<'a, T> Drop for Drain<'a, T> {
+impl<'a, T, A: Allocator, G: GrowthPolicy> Drop for Drain<'a, T, A, G> {
     fn drop(&mut self) {
-        while let Some(_) = self.next() {}
+        struct DropGuard<'r, 'a: 'r, T: 'a, A: 'a + Allocator, G: 'a + GrowthPolicy>(&'r mut Drain<'a, T, A, G>);
+
+        impl<'r, 'a, T, A: Allocator, G: GrowthPolicy> Drop for DropGuard<'r, 'a, T, A, G> {
+            fn drop(&mut self) {
+                if self.0.tail_len > 0 {
+                    unsafe {
+                        let source_vec = self.0.vec.as_mut();
+                        let start = source_vec.len;
+                        let tail = self.0.tail_start;
+                        if tail != start {
+                            let ptr = source_vec.raw.ptr();
+                            ptr::copy(ptr.add(tail), ptr.add(start), self.0.tail_len);
+                        }
+                        source_vec.set_len(start + self.0.tail_len);
+                    }
+                }
+            }
+        }
 
-        if self.tail_len > 0 {
-            unsafe {
-                let source_vec = &mut *(self.vec.as_ptr());
-                let start = source_vec.len();
-                let tail = self.tail_start;
-                let src = source_vec.as_ptr().offset(tail as isize);
-                let dst = (source_vec.as_ptr() as *mut _).offset(start as isize);
-                ptr::copy(src, dst, self.tail_len);
-                source_vec.set_len(start + self.tail_len);
+        let iter = mem::take(&mut self.iter);
+        let drop_len = iter.len();
+        let mut vec = self.vec;
+
+        let _guard = DropGuard(self);
+
+        if drop_len == 0 {
+            return;
+        }
+
+        unsafe {
+            let vec_ptr = vec.as_mut().raw.ptr();
+            let drop_offset = if mem::size_of::<T>() == 0 {
+                0
+            } else {
+                iter.as_slice().as_ptr().offset_from(vec_ptr) as usize
+            };
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(vec_ptr.add(drop_offset), drop_len));
+        }
+    }
+}
+
+impl<'a, T, A: Allocator, G: GrowthPolicy> ExactSizeIterator for Drain<'a, T, A, G> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, T, A: Allocator, G: GrowthPolicy> FusedIterator for Drain<'a, T, A, G> {}
+
+#[cfg(feature = "nightly")]
+unsafe impl<'a, T, A: Allocator, G: GrowthPolicy> TrustedLen for Drain<'a, T, A, G> {}
+
+impl<'a, T, A: Allocator, G: GrowthPolicy> Drain<'a, T, A, G> {
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+    #[inline(always)]
+    pub fn allocator(&self) -> &A {
+        unsafe { self.vec.as_ref().allocator() }
+    }
+    pub fn keep_rest(self) {
+        let mut this = mem::ManuallyDrop::new(self);
+
+        unsafe {
+            let source_vec = this.vec.as_mut();
+            let start = source_vec.len;
+            let start_ptr = source_vec.raw.ptr().add(start);
+
+            let unyielded_len = this.iter.len();
+            let unyielded_ptr = this.iter.as_slice().as_ptr();
+
+            if mem::size_of::<T>() != 0 && !ptr::eq(unyielded_ptr, start_ptr) {
+                ptr::copy(unyielded_ptr, start_ptr, unyielded_len);
+            }
+            if this.tail_len != 0 {
+                let src = source_vec.raw.ptr().add(this.tail_start);
+                ptr::copy(src, start_ptr.add(unyielded_len), this.tail_len);
+            }
+            source_vec.set_len(start + unyielded_len + this.tail_len);
+        }
+    }
+    unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
+        let vec = self.vec.as_mut();
+        let range_start = vec.len;
+        let range_end = self.tail_start;
+
+        for i in range_start..range_end {
+            if let Some(element) = replace_with.next() {
+                ptr::write(vec.raw.ptr().add(i), element);
+                vec.len += 1;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+    unsafe fn move_tail(&mut self, additional: usize) {
+        let vec = self.vec.as_mut();
+        let len = self.tail_start + self.tail_len;
+        vec.raw.reserve(len, additional);
+
+        let new_tail_start = self.tail_start + additional;
+        let ptr = vec.raw.ptr();
+        ptr::copy(ptr.add(self.tail_start), ptr.add(new_tail_start), self.tail_len);
+        self.tail_start = new_tail_start;
+    }
+}
+
+
+pub struct Splice<'a, I: Iterator + 'a, A: 'a + Allocator = Global, G: 'a + GrowthPolicy = Doubling> {
+    drain: Drain<'a, I::Item, A, G>,
+    replace_with: I,
+}
+
+impl<'a, I: Iterator, A: Allocator, G: GrowthPolicy> Iterator for Splice<'a, I, A, G> {
+    type Item = I::Item;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<I::Item> {
+        self.drain.next()
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl<'a, I: Iterator, A: Allocator, G: GrowthPolicy> DoubleEndedIterator for Splice<'a, I, A, G> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<I::Item> {
+        self.drain.next_back()
+    }
+}
+
+impl<'a, I: Iterator, A: Allocator, G: GrowthPolicy> ExactSizeIterator for Splice<'a, I, A, G> {}
+
+impl<'a, I: Iterator, A: Allocator, G: GrowthPolicy> Drop for Splice<'a, I, A, G> {
+    fn drop(&mut self) {
+        for _x in self.drain.by_ref() {}
+        self.drain.iter = [].iter();
+
+        unsafe {
+            if self.drain.tail_len == 0 {
+                self.drain.vec.as_mut().extend(self.replace_with.by_ref());
+                return;
+            }
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+
+            let (lower, _) = self.replace_with.size_hint();
+            if lower > 0 {
+                self.drain.move_tail(lower);
+                if !self.drain.fill(&mut self.replace_with) {
+                    return;
+                }
+            }
+
+            let mut collected = self.replace_with.by_ref().collect::<Vector<I::Item>>().into_iter();
+            if collected.len() > 0 {
+                self.drain.move_tail(collected.len());
+                let filled = self.drain.fill(&mut collected);
+                debug_assert!(filled);
+            }
+        }
+    }
+}
+
+
+pub struct ExtractIf<'a, T: 'a, F, A: 'a + Allocator = Global, G: 'a + GrowthPolicy = Doubling>
+    where F: FnMut(&mut T) -> bool
+{
+    vec: &'a mut Vector<T, A, G>,
+    idx: usize,
+    end: usize,
+    del: usize,
+    old_len: usize,
+    pred: F,
+}
+
+impl<'a, T, F, A: Allocator, G: GrowthPolicy> Iterator for ExtractIf<'a, T, F, A, G>
+    where F: FnMut(&mut T) -> bool
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            while self.idx < self.end {
+                let i = self.idx;
+                let v = slice::from_raw_parts_mut(self.vec.raw.ptr(), self.old_len);
+                let drained = (self.pred)(&mut v[i]);
+                self.idx += 1;
+
+                if drained {
+                    self.del += 1;
+                    return Some(ptr::read(&v[i]));
+                } else if self.del > 0 {
+                    let del = self.del;
+                    let src: *const T = &v[i];
+                    let dst: *mut T = &mut v[i - del];
+                    ptr::copy_nonoverlapping(src, dst, 1);
+                }
+            }
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<'a, T, F, A: Allocator, G: GrowthPolicy> Drop for ExtractIf<'a, T, F, A, G>
+    where F: FnMut(&mut T) -> bool
+{
+    fn drop(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                let ptr = self.vec.raw.ptr();
+                let src = ptr.add(self.idx);
+                ptr::copy(src, src.sub(self.del), self.old_len - self.idx);
             }
+            self.vec.set_len(self.old_len - self.del);
         }
     }
 }
 
-impl<'a, T> ExactSizeIterator for Drain<'a, T> {
-    fn is_empty(&self) -> bool {
-        self.iter.is_empty()
+impl<'a, T: fmt::Debug, A: Allocator, G: GrowthPolicy> fmt::Debug for Drain<'a, T, A, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Drain")
+            .field(&self.iter.as_slice())
+            .finish()
     }
 }
 
-impl<'a, T> FusedIterator for Drain<'a, T> {}
+impl<'a, T: fmt::Debug, F, A: Allocator, G: GrowthPolicy> fmt::Debug for ExtractIf<'a, T, F, A, G>
+    where F: FnMut(&mut T) -> bool
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let peek = if self.idx < self.end {
+            unsafe { Some(&*self.vec.raw.ptr().add(self.idx)) }
+        } else {
+            None
+        };
+        f.debug_struct("ExtractIf").field("peek", &peek).finish_non_exhaustive()
+    }
+}
 
 
 #[inline]
 pub fn from_elem<T: Clone>(elem: T, n: usize) -> Vector<T> {
-    let mut v = Vector::with_capacity(n);
+    from_elem_in(elem, n, Global)
+}
+
+#[inline]
+pub fn from_elem_in<T: Clone, A: Allocator>(elem: T, n: usize, alloc: A) -> Vector<T, A> {
+    let mut v = Vector::with_capacity_in(n, alloc);
     v.extend_with_element(n, elem);
     v
 }
+
+#[inline]
+pub(crate) fn range_bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x.checked_add(1).expect("range start overflows usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&x) => x.checked_add(1).expect("range end overflows usize"),
+        Bound::Excluded(&x) => x,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start is greater than range end");
+    assert!(end <= len, "range end out of bounds");
+    (start, end)
+}
+
+/// # Safety
+///
+/// `ptr` must point to `*len` initialized elements of `T`.
+pub(crate) unsafe fn retain_raw<T, F>(ptr: *mut T, len: &mut usize, mut f: F)
+    where F: FnMut(&mut T) -> bool
+{
+    struct BackshiftOnDrop<'a, T> {
+        ptr: *mut T,
+        len: &'a mut usize,
+        processed: usize,
+        deleted: usize,
+        original_len: usize,
+    }
+
+    impl<'a, T> Drop for BackshiftOnDrop<'a, T> {
+        fn drop(&mut self) {
+            unsafe {
+                if self.deleted > 0 {
+                    ptr::copy(
+                        self.ptr.add(self.processed),
+                        self.ptr.add(self.processed - self.deleted),
+                        self.original_len - self.processed
+                    );
+                }
+            }
+            *self.len = self.original_len - self.deleted;
+        }
+    }
+
+    let original_len = *len;
+    *len = 0;
+
+    let mut guard = BackshiftOnDrop {
+        ptr,
+        len,
+        processed: 0,
+        deleted: 0,
+        original_len,
+    };
+
+    while guard.processed != original_len {
+        let current = ptr.add(guard.processed);
+
+        if !f(&mut *current) {
+            guard.processed += 1;
+            guard.deleted += 1;
+            ptr::drop_in_place(current);
+        } else {
+            if guard.deleted > 0 {
+                ptr::copy_nonoverlapping(current, ptr.add(guard.processed - guard.deleted), 1);
+            }
+            guard.processed += 1;
+        }
+    }
+}
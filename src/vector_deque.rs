@@ -0,0 +1,581 @@
+use core::{cmp, fmt, ptr, slice, mem};
+use core::ops::*;
+use core::hash::{self, Hash};
+use core::cmp::Ordering;
+use core::iter::{Chain, FusedIterator, FromIterator};
+
+use collection_traits::*;
+
+use super::raw_buffer::RawBuffer;
+use super::vector::Vector;
+
+
+pub struct VectorDeque<T> {
+    raw: RawBuffer<T>,
+    head: usize,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for VectorDeque<T> {}
+unsafe impl<T: Sync> Sync for VectorDeque<T> {}
+
+impl<T> VectorDeque<T> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        VectorDeque {
+            raw: RawBuffer::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+    #[inline(always)]
+    pub fn with_capacity(cap: usize) -> Self {
+        VectorDeque {
+            raw: RawBuffer::with_capacity(cap),
+            head: 0,
+            len: 0,
+        }
+    }
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.raw.cap()
+    }
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let old_cap = self.raw.cap();
+        self.raw.reserve(self.len, additional);
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+    }
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let old_cap = self.raw.cap();
+        self.raw.reserve_exact(self.len, additional);
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+    }
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.make_contiguous();
+        self.raw.shrink_to_fit(self.len);
+    }
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len {
+            unsafe {
+                Some(&*self.raw.ptr().add(self.to_physical(index)))
+            }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            unsafe {
+                Some(&mut *self.raw.ptr().add(self.to_physical(index)))
+            }
+        } else {
+            None
+        }
+    }
+    #[inline]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len && j < self.len);
+        unsafe {
+            let a = self.raw.ptr().add(self.to_physical(i));
+            let b = self.raw.ptr().add(self.to_physical(j));
+            ptr::swap(a, b);
+        }
+    }
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (head_len, tail_len) = self.slice_lens();
+        unsafe {
+            (slice::from_raw_parts(self.raw.ptr().add(self.head), head_len),
+             slice::from_raw_parts(self.raw.ptr(), tail_len))
+        }
+    }
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head_len, tail_len) = self.slice_lens();
+        unsafe {
+            (slice::from_raw_parts_mut(self.raw.ptr().add(self.head), head_len),
+             slice::from_raw_parts_mut(self.raw.ptr(), tail_len))
+        }
+    }
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let (head_len, tail_len) = self.slice_lens();
+
+        unsafe {
+            let ptr = self.raw.ptr();
+
+            if tail_len == 0 {
+                if self.head != 0 {
+                    ptr::copy(ptr.add(self.head), ptr, head_len);
+                }
+            } else {
+                ptr::copy(ptr.add(self.head), ptr.add(tail_len), head_len);
+                slice::from_raw_parts_mut(ptr, self.len).rotate_left(tail_len);
+            }
+            self.head = 0;
+
+            slice::from_raw_parts_mut(ptr, self.len)
+        }
+    }
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        while len < self.len {
+            self.pop_back();
+        }
+    }
+    #[inline]
+    pub fn append(&mut self, other: &mut Self) {
+        self.reserve(other.len);
+        while let Some(element) = other.pop_front() {
+            self.push_back(element);
+        }
+    }
+
+    #[inline(always)]
+    fn to_physical(&self, index: usize) -> usize {
+        let cap = self.raw.cap();
+        if index >= cap - self.head {
+            index - (cap - self.head)
+        } else {
+            self.head + index
+        }
+    }
+    #[inline(always)]
+    fn slice_lens(&self) -> (usize, usize) {
+        let head_len = cmp::min(self.len, self.raw.cap() - self.head);
+        (head_len, self.len - head_len)
+    }
+    #[inline]
+    fn grow(&mut self) {
+        let old_cap = self.raw.cap();
+        self.raw.double();
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+    }
+    #[inline]
+    unsafe fn handle_capacity_increase(&mut self, old_cap: usize) {
+        let new_cap = self.raw.cap();
+
+        if old_cap == new_cap || self.head + self.len <= old_cap {
+            return;
+        }
+
+        let ptr = self.raw.ptr();
+        let head_len = old_cap - self.head;
+        let tail_len = self.len - head_len;
+
+        if tail_len <= new_cap - old_cap {
+            ptr::copy_nonoverlapping(ptr, ptr.add(old_cap), tail_len);
+        } else {
+            let new_head = new_cap - head_len;
+            ptr::copy(ptr.add(self.head), ptr.add(new_head), head_len);
+            self.head = new_head;
+        }
+    }
+}
+
+impl<T: Clone> VectorDeque<T> {
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len > self.len {
+            let additional = new_len - self.len;
+            self.reserve(additional);
+            for _ in 0..additional {
+                self.push_back(value.clone());
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+impl<T> Default for VectorDeque<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for VectorDeque<T> {
+    #[inline]
+    fn drop(&mut self) {
+        struct Dropper<'a, T: 'a>(&'a mut [T]);
+
+        impl<'a, T> Drop for Dropper<'a, T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(self.0);
+                }
+            }
+        }
+
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            let _back = Dropper(back);
+            ptr::drop_in_place(front);
+        }
+    }
+}
+
+impl<T: Clone> Clone for VectorDeque<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<A: PartialEq<B>, B> PartialEq<VectorDeque<B>> for VectorDeque<A> {
+    #[inline]
+    fn eq(&self, other: &VectorDeque<B>) -> bool {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: Eq> Eq for VectorDeque<T> {}
+
+impl<T: PartialOrd> PartialOrd for VectorDeque<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &VectorDeque<T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for VectorDeque<T> {
+    #[inline]
+    fn cmp(&self, other: &VectorDeque<T>) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for VectorDeque<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for VectorDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for VectorDeque<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+impl<T> IndexMut<usize> for VectorDeque<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+impl<T> Collection for VectorDeque<T> {
+    #[inline(always)]
+    fn len(&self) -> usize { self.len }
+}
+
+impl<T> CollectionMut for VectorDeque<T> {
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+}
+
+impl<T> InsertMut<usize, T> for VectorDeque<T> {
+    type Output = ();
+
+    #[inline]
+    fn insert(&mut self, index: usize, element: T) -> Self::Output {
+        let len = self.len;
+        assert!(index <= len);
+
+        if index < len - index {
+            self.push_front(element);
+            for i in 0..index {
+                self.swap(i, i + 1);
+            }
+        } else {
+            self.push_back(element);
+            for i in (index..len).rev() {
+                self.swap(i, i + 1);
+            }
+        }
+    }
+}
+
+impl<T> RemoveMut<usize> for VectorDeque<T> {
+    type Output = T;
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        assert!(index < len);
+
+        if index < len - index - 1 {
+            for i in (0..index).rev() {
+                self.swap(i, i + 1);
+            }
+            self.pop_front().unwrap()
+        } else {
+            for i in index..(len - 1) {
+                self.swap(i, i + 1);
+            }
+            self.pop_back().unwrap()
+        }
+    }
+}
+
+impl<T> DequeMut<T> for VectorDeque<T> {
+    #[inline]
+    fn push_front(&mut self, element: T) {
+        if self.len == self.raw.cap() {
+            self.grow();
+        }
+        self.head = if self.head == 0 {
+            self.raw.cap() - 1
+        } else {
+            self.head - 1
+        };
+        self.len += 1;
+        unsafe {
+            ptr::write(self.raw.ptr().add(self.head), element);
+        }
+    }
+    #[inline]
+    fn push_back(&mut self, element: T) {
+        if self.len == self.raw.cap() {
+            self.grow();
+        }
+        unsafe {
+            ptr::write(self.raw.ptr().add(self.to_physical(self.len)), element);
+        }
+        self.len += 1;
+    }
+    #[inline]
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let old_head = self.head;
+            self.head = self.to_physical(1);
+            self.len -= 1;
+            unsafe {
+                Some(ptr::read(self.raw.ptr().add(old_head)))
+            }
+        }
+    }
+    #[inline]
+    fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe {
+                Some(ptr::read(self.raw.ptr().add(self.to_physical(self.len))))
+            }
+        }
+    }
+    #[inline(always)]
+    fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+    #[inline]
+    fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+    #[inline(always)]
+    fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+    #[inline]
+    fn back_mut(&mut self) -> Option<&mut T> {
+        match self.len.checked_sub(1) {
+            Some(index) => self.get_mut(index),
+            None => None,
+        }
+    }
+}
+
+impl<T> StackMut<T> for VectorDeque<T> {
+    #[inline(always)]
+    fn push(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn pop(&mut self) -> Option<T> { self.pop_back() }
+    #[inline(always)]
+    fn top(&self) -> Option<&T> { self.back() }
+    #[inline(always)]
+    fn top_mut(&mut self) -> Option<&mut T> { self.back_mut() }
+}
+
+impl<T> QueueMut<T> for VectorDeque<T> {
+    #[inline(always)]
+    fn enqueue(&mut self, element: T) { self.push_back(element) }
+    #[inline(always)]
+    fn dequeue(&mut self) -> Option<T> { self.pop_front() }
+    #[inline(always)]
+    fn peek(&self) -> Option<&T> { self.front() }
+    #[inline(always)]
+    fn peek_mut(&mut self) -> Option<&mut T> { self.front_mut() }
+}
+
+pub type Iter<'a, T> = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+pub type IterMut<'a, T> = Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+impl<'a, T: 'a> Iterable<'a, &'a T> for VectorDeque<T> {
+    type Iter = Iter<'a, T>;
+
+    #[inline(always)]
+    fn iter(&'a self) -> Self::Iter {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back.iter())
+    }
+}
+
+impl<'a, T: 'a> IterableMut<'a, &'a mut T> for VectorDeque<T> {
+    type IterMut = IterMut<'a, T>;
+
+    #[inline(always)]
+    fn iter_mut(&'a mut self) -> Self::IterMut {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back.iter_mut())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a VectorDeque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut VectorDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for VectorDeque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            deque: self,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for VectorDeque<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> VectorDeque<T> {
+        From::from(iter.into_iter().collect::<Vector<T>>())
+    }
+}
+
+impl<T> Extend<T> for VectorDeque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iterator = iter.into_iter();
+        let (lower, _) = iterator.size_hint();
+        self.reserve(lower);
+        for element in iterator {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for VectorDeque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned())
+    }
+}
+
+impl<T> From<Vector<T>> for VectorDeque<T> {
+    #[inline]
+    fn from(vector: Vector<T>) -> VectorDeque<T> {
+        let (raw, len) = vector.into_raw_buffer();
+        VectorDeque {
+            raw,
+            head: 0,
+            len,
+        }
+    }
+}
+
+impl<T> From<VectorDeque<T>> for Vector<T> {
+    #[inline]
+    fn from(mut deque: VectorDeque<T>) -> Vector<T> {
+        deque.make_contiguous();
+
+        unsafe {
+            let raw = ptr::read(&deque.raw);
+            let len = deque.len;
+            mem::forget(deque);
+            Vector::from_raw_buffer(raw, len)
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IntoIter<T> {
+    deque: VectorDeque<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIter")
+            .field(&self.deque)
+            .finish()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
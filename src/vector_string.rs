@@ -0,0 +1,509 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::{fmt, hash, ptr, str};
+use core::borrow::{Borrow, BorrowMut};
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::{Deref, DerefMut, RangeBounds};
+use core::str::{Chars, Utf8Error};
+
+use super::vector::{range_bounds, Vector};
+
+
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VectorString {
+    vector: Vector<u8>,
+}
+
+impl VectorString {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        VectorString { vector: Vector::new() }
+    }
+    #[inline(always)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        VectorString { vector: Vector::with_capacity(capacity) }
+    }
+    #[inline]
+    pub fn from_utf8(vector: Vector<u8>) -> Result<Self, FromUtf8Error> {
+        match str::from_utf8(&vector) {
+            Ok(_) => Ok(VectorString { vector }),
+            Err(error) => Err(FromUtf8Error { bytes: vector, error }),
+        }
+    }
+    #[inline]
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => VectorString::from(s),
+            Cow::Owned(s) => VectorString::from(s),
+        }
+    }
+    /// # Safety
+    ///
+    /// `vector` must contain valid UTF-8.
+    #[inline(always)]
+    pub unsafe fn from_utf8_unchecked(vector: Vector<u8>) -> Self {
+        VectorString { vector }
+    }
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vector<u8> {
+        self.vector
+    }
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(&self.vector) }
+    }
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(&mut self.vector) }
+    }
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.vector
+    }
+    /// # Safety
+    ///
+    /// The bytes must still be valid UTF-8 when the borrow ends.
+    #[inline(always)]
+    pub unsafe fn as_mut_vector(&mut self) -> &mut Vector<u8> {
+        &mut self.vector
+    }
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.vector.is_empty()
+    }
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.vector.capacity()
+    }
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vector.reserve(additional)
+    }
+    #[inline(always)]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vector.reserve_exact(additional)
+    }
+    #[inline(always)]
+    pub fn shrink_to_fit(&mut self) {
+        self.vector.shrink_to_fit()
+    }
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.vector.clear()
+    }
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        match ch.len_utf8() {
+            1 => self.vector.push(ch as u8),
+            _ => self.vector.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+    #[inline(always)]
+    pub fn push_str(&mut self, string: &str) {
+        self.vector.extend_from_slice(string.as_bytes())
+    }
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe {
+            self.vector.set_len(new_len);
+        }
+        Some(ch)
+    }
+    #[inline]
+    pub fn insert(&mut self, index: usize, ch: char) {
+        self.insert_str(index, ch.encode_utf8(&mut [0; 4]))
+    }
+    #[inline]
+    pub fn insert_str(&mut self, index: usize, string: &str) {
+        assert!(self.is_char_boundary(index), "byte index {} is not a char boundary", index);
+
+        let len = self.len();
+        let amt = string.len();
+        self.vector.reserve(amt);
+
+        unsafe {
+            let ptr = self.vector.as_mut_ptr();
+            ptr::copy(ptr.add(index), ptr.add(index + amt), len - index);
+            ptr::copy_nonoverlapping(string.as_ptr(), ptr.add(index), amt);
+            self.vector.set_len(len + amt);
+        }
+    }
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> char {
+        let ch = match self[index..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+
+        let next = index + ch.len_utf8();
+        let len = self.len();
+        unsafe {
+            let ptr = self.vector.as_mut_ptr();
+            ptr::copy(ptr.add(next), ptr.add(index), len - next);
+            self.vector.set_len(len - (next - index));
+        }
+        ch
+    }
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(self.is_char_boundary(new_len), "new_len {} is not a char boundary", new_len);
+            self.vector.truncate(new_len)
+        }
+    }
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> VectorString {
+        assert!(self.is_char_boundary(at), "`at` {} is not a char boundary", at);
+        VectorString { vector: self.vector.split_off(at) }
+    }
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(char) -> bool
+    {
+        struct SetLenOnDrop<'a> {
+            string: &'a mut VectorString,
+            index: usize,
+            deleted: usize,
+        }
+
+        impl<'a> Drop for SetLenOnDrop<'a> {
+            fn drop(&mut self) {
+                unsafe {
+                    self.string.vector.set_len(self.index - self.deleted);
+                }
+            }
+        }
+
+        let len = self.len();
+        let mut guard = SetLenOnDrop { string: self, index: 0, deleted: 0 };
+
+        while guard.index < len {
+            let ch = unsafe {
+                guard.string.get_unchecked(guard.index..len).chars().next().unwrap_unchecked()
+            };
+            let ch_len = ch.len_utf8();
+
+            if !f(ch) {
+                guard.deleted += ch_len;
+            } else if guard.deleted > 0 {
+                unsafe {
+                    let ptr = guard.string.vector.as_mut_ptr();
+                    ptr::copy(ptr.add(guard.index), ptr.add(guard.index - guard.deleted), ch_len);
+                }
+            }
+            guard.index += ch_len;
+        }
+    }
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_>
+        where R: RangeBounds<usize>
+    {
+        let (start, end) = range_bounds(&range, self.len());
+        assert!(self.is_char_boundary(start), "start {} is not a char boundary", start);
+        assert!(self.is_char_boundary(end), "end {} is not a char boundary", end);
+
+        let string = self as *mut VectorString;
+        let iter = unsafe { (&*string)[start..end].chars() };
+
+        Drain { string, start, end, iter }
+    }
+    #[inline(always)]
+    pub fn into_boxed_str(self) -> Box<str> {
+        String::from(self).into_boxed_str()
+    }
+}
+
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct FromUtf8Error {
+    bytes: Vector<u8>,
+    error: Utf8Error,
+}
+
+impl FromUtf8Error {
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    #[inline(always)]
+    pub fn into_bytes(self) -> Vector<u8> {
+        self.bytes
+    }
+    #[inline(always)]
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl fmt::Debug for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromUtf8Error")
+            .field("bytes", &self.bytes)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+
+pub struct Drain<'a> {
+    string: *mut VectorString,
+    start: usize,
+    end: usize,
+    iter: Chars<'a>,
+}
+
+unsafe impl<'a> Sync for Drain<'a> {}
+unsafe impl<'a> Send for Drain<'a> {}
+
+impl<'a> Drain<'a> {
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.iter.as_str()
+    }
+}
+
+impl<'a> fmt::Debug for Drain<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_str()).finish()
+    }
+}
+
+impl<'a> Iterator for Drain<'a> {
+    type Item = char;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<char> {
+        self.iter.next()
+    }
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Drain<'a> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<char> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a> FusedIterator for Drain<'a> {}
+
+impl<'a> Drop for Drain<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            (*self.string).vector.drain(self.start..self.end);
+        }
+    }
+}
+
+
+impl Deref for VectorString {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl DerefMut for VectorString {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<str> for VectorString {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl AsMut<str> for VectorString {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl AsRef<[u8]> for VectorString {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for VectorString {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl BorrowMut<str> for VectorString {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl hash::Hash for VectorString {
+    #[inline(always)]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+impl fmt::Debug for VectorString {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for VectorString {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Write for VectorString {
+    #[inline(always)]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+    #[inline(always)]
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        self.push(ch);
+        Ok(())
+    }
+}
+
+macro_rules! impl_str_eq {
+    ($([$($lt:tt)*] $rhs:ty),*) => {
+        $(
+            impl<$($lt)*> PartialEq<$rhs> for VectorString {
+                #[inline(always)]
+                fn eq(&self, other: &$rhs) -> bool { self.as_str() == &other[..] }
+            }
+
+            impl<$($lt)*> PartialEq<VectorString> for $rhs {
+                #[inline(always)]
+                fn eq(&self, other: &VectorString) -> bool { &self[..] == other.as_str() }
+            }
+        )*
+    };
+}
+
+impl_str_eq! { [] str, ['a] &'a str, [] String, ['a] Cow<'a, str> }
+
+impl<'a> From<&'a str> for VectorString {
+    #[inline(always)]
+    fn from(s: &'a str) -> Self {
+        VectorString { vector: Vector::from(s) }
+    }
+}
+
+impl From<String> for VectorString {
+    #[inline(always)]
+    fn from(s: String) -> Self {
+        VectorString { vector: Vector::from(s) }
+    }
+}
+
+impl From<char> for VectorString {
+    #[inline]
+    fn from(ch: char) -> Self {
+        let mut string = VectorString::new();
+        string.push(ch);
+        string
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for VectorString {
+    #[inline]
+    fn from(cow: Cow<'a, str>) -> Self {
+        match cow {
+            Cow::Borrowed(s) => VectorString::from(s),
+            Cow::Owned(s) => VectorString::from(s),
+        }
+    }
+}
+
+impl From<VectorString> for String {
+    #[inline]
+    fn from(string: VectorString) -> String {
+        unsafe { String::from_utf8_unchecked(Vec::from(string.vector)) }
+    }
+}
+
+impl From<VectorString> for Vector<u8> {
+    #[inline(always)]
+    fn from(string: VectorString) -> Vector<u8> {
+        string.vector
+    }
+}
+
+impl<'a> From<&'a VectorString> for Cow<'a, str> {
+    #[inline(always)]
+    fn from(string: &'a VectorString) -> Cow<'a, str> {
+        Cow::Borrowed(string.as_str())
+    }
+}
+
+impl Extend<char> for VectorString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl<'a> Extend<&'a char> for VectorString {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned())
+    }
+}
+
+impl<'a> Extend<&'a str> for VectorString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for VectorString
+    where VectorString: Extend<T>
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut string = VectorString::new();
+        string.extend(iter);
+        string
+    }
+}
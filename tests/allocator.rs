@@ -0,0 +1,168 @@
+#[macro_use]
+extern crate vector;
+
+
+use std::alloc::Layout;
+use std::cell::{Cell, UnsafeCell};
+use std::ptr::NonNull;
+
+use vector::{Allocator, AllocError, Global, TryReserveError, Vector};
+
+
+#[derive(Default)]
+struct Counting {
+    allocated: Cell<usize>,
+    deallocated: Cell<usize>,
+}
+
+unsafe impl Allocator for Counting {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.allocated.set(self.allocated.get() + 1);
+        Global.allocate(layout)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocated.set(self.deallocated.get() + 1);
+        Global.deallocate(ptr, layout)
+    }
+}
+
+impl Counting {
+    fn live(&self) -> usize {
+        self.allocated.get() - self.deallocated.get()
+    }
+}
+
+struct Arena {
+    memory: UnsafeCell<[u64; 64]>,
+    used: Cell<usize>,
+}
+
+unsafe impl Allocator for Arena {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = start + layout.size();
+        if layout.align() > 8 || end > 64 * 8 {
+            return Err(AllocError);
+        }
+        self.used.set(end);
+        unsafe {
+            Ok(NonNull::new_unchecked((self.memory.get() as *mut u8).add(start)))
+        }
+    }
+    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+}
+
+
+#[test]
+fn test_allocator_counts() {
+    let alloc = Counting::default();
+    {
+        let mut a = Vector::new_in(&alloc);
+        for i in 0..32 {
+            a.push(i);
+        }
+        assert!(alloc.allocated.get() > 0);
+
+        let b = a.clone();
+        let c = a.split_off(16);
+        assert_eq!(b.len(), 32);
+        assert_eq!(c, (16..32).collect::<Vector<_>>());
+        assert!(std::ptr::eq(*c.allocator(), &alloc));
+
+        let mut d = Vector::with_capacity_in(8, &alloc);
+        d.extend(a.drain(..4));
+        assert_eq!(d, [0, 1, 2, 3]);
+
+        let mut iter = b.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        assert!(std::ptr::eq(*iter.allocator(), &alloc));
+    }
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_allocator_macro() {
+    let alloc = Counting::default();
+    {
+        let a = vector![in &alloc; 1, 2, 3];
+        let b = vector![in &alloc; 0usize; 4];
+        assert_eq!(a, [1, 2, 3]);
+        assert_eq!(b, [0, 0, 0, 0]);
+    }
+    assert_eq!(alloc.allocated.get(), 2);
+    assert_eq!(alloc.live(), 0);
+}
+
+#[test]
+fn test_allocator_arena() {
+    let arena = Arena {
+        memory: UnsafeCell::new([0; 64]),
+        used: Cell::new(0),
+    };
+    let base = arena.memory.get() as usize;
+
+    let mut a = Vector::with_capacity_in(4, &arena);
+    a.extend([1u32, 2, 3, 4]);
+    let address = a.as_ptr() as usize;
+    assert!(address >= base && address < base + 64 * 8);
+
+    a.push(5);
+    assert_eq!(a, [1, 2, 3, 4, 5]);
+}
+
+struct Limited {
+    remaining: Cell<usize>,
+}
+
+unsafe impl Allocator for Limited {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        if layout.size() > self.remaining.get() {
+            return Err(AllocError);
+        }
+        self.remaining.set(self.remaining.get() - layout.size());
+        Global.allocate(layout)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.remaining.set(self.remaining.get() + layout.size());
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut a = Vector::<u64>::new();
+    assert_eq!(a.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(a.try_reserve_exact(usize::MAX / 4), Err(TryReserveError::CapacityOverflow));
+    assert!(Vector::<u64>::try_with_capacity(usize::MAX).is_err());
+    assert_eq!(a.capacity(), 0);
+
+    let limited = Limited { remaining: Cell::new(64) };
+    let mut b = Vector::try_with_capacity_in(8, &limited).unwrap();
+    for i in 0..8u64 {
+        b.try_push(i).unwrap();
+    }
+    match b.try_push(8) {
+        Err(TryReserveError::AllocError { layout }) => assert_eq!(layout.size(), 128),
+        result => panic!("unexpected {:?}", result),
+    }
+    assert_eq!(b, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert!(b.try_insert(0, 100).is_err());
+    assert!(b.try_resize(16, 0).is_err());
+    assert!(b.try_clone().is_err());
+    assert_eq!(b.len(), 8);
+}
+
+#[test]
+fn test_try_extend() {
+    let limited = Limited { remaining: Cell::new(16 * 8) };
+    let mut a = Vector::with_capacity_in(4, &limited);
+    a.extend([1u64, 2]);
+
+    assert!(a.try_extend(0..100u64).is_err());
+    assert_eq!(a, [1, 2]);
+    a.try_extend(3..5).unwrap();
+    assert_eq!(a, [1, 2, 3, 4]);
+
+    assert!(Vector::try_from_iter_in(0..100u64, &limited).is_err());
+    assert_eq!(Vector::try_from_iter(0..4u64).unwrap(), [0, 1, 2, 3]);
+}
@@ -0,0 +1,142 @@
+#[macro_use]
+extern crate vector;
+extern crate collection_traits;
+
+
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use vector::{ArrayVector, CapacityError};
+use collection_traits::*;
+
+
+const PRIMES: ArrayVector<u32, 8> = array_vector![2, 3, 5, 7];
+const EMPTY: ArrayVector<u32, 4> = array_vector![];
+
+#[test]
+fn test_const_macro() {
+    assert_eq!(PRIMES, [2, 3, 5, 7]);
+    assert_eq!(PRIMES.capacity(), 8);
+    assert!(EMPTY.is_empty());
+
+    let zeros: ArrayVector<u8, 4> = array_vector![0; 3];
+    assert_eq!(zeros, [0, 0, 0]);
+}
+
+#[test]
+fn test_try_growth() {
+    let mut v = ArrayVector::<u32, 3>::new();
+
+    assert_eq!(v.try_push(1), Ok(()));
+    assert_eq!(v.try_insert(0, 0), Ok(()));
+    assert_eq!(v.try_extend(2..10).map_err(CapacityError::element), Err(3));
+    assert!(v.is_full());
+    assert_eq!(v.try_push(9).map_err(CapacityError::element), Err(9));
+    assert_eq!(v.try_insert(1, 8).map_err(CapacityError::element), Err(8));
+    assert_eq!(v.try_resize(4, 7).map_err(CapacityError::element), Err(7));
+    assert_eq!(v, [0, 1, 2]);
+
+    assert_eq!(v.remove(0), 0);
+    assert_eq!(v.pop(), Some(2));
+    v.resize(3, 5);
+    assert_eq!(v.into_inner(), Ok([1, 5, 5]));
+}
+
+#[test]
+fn test_methods() {
+    let mut v = (0..10).collect::<ArrayVector<u32, 16>>();
+
+    v.retain(|&x| x % 3 != 0);
+    assert_eq!(v, [1, 2, 4, 5, 7, 8]);
+    assert_eq!(v.drain(..2).collect::<Vec<_>>(), [1, 2]);
+
+    let mut tail = v.split_off(2);
+    assert_eq!(v, [4, 5]);
+    assert_eq!(tail, [7, 8]);
+
+    v.append(&mut tail);
+    assert!(tail.is_empty());
+    assert_eq!(v.clone(), [4, 5, 7, 8]);
+    assert_eq!(v.into_iter().rev().collect::<Vec<_>>(), [8, 7, 5, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_push_overflow() {
+    let mut v = ArrayVector::<u32, 1>::new();
+    v.push(1);
+    v.push(2);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+
+    let mut v = ArrayVector::<Rc<()>, 4>::new();
+    v.extend((0..3).map(|_| rc.clone()));
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let mut iter = v.into_iter();
+    iter.next_back();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_full_drain_panic_then_try_push() {
+    struct PanicOnDrop {
+        id: u32,
+        _rc: Rc<()>,
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.id == 1 {
+                panic!("element panicked");
+            }
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut v = ArrayVector::<_, 4>::new();
+    v.try_extend((0..4).map(|id| PanicOnDrop { id, _rc: rc.clone() })).ok().unwrap();
+    assert!(v.is_full());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        v.drain(1..3);
+    }));
+    assert!(result.is_err());
+    assert_eq!(v.iter().map(|x| x.id).collect::<Vec<_>>(), [0, 3]);
+    assert_eq!(v.remaining_capacity(), 2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    assert!(v.try_push(PanicOnDrop { id: 4, _rc: rc.clone() }).is_ok());
+    assert!(v.try_push(PanicOnDrop { id: 5, _rc: rc.clone() }).is_ok());
+    let err = v.try_push(PanicOnDrop { id: 6, _rc: rc.clone() }).err().unwrap();
+    assert_eq!(err.element().id, 6);
+    assert_eq!(v.iter().map(|x| x.id).collect::<Vec<_>>(), [0, 3, 4, 5]);
+
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+mod inherent {
+    use vector::ArrayVector;
+
+
+    #[test]
+    fn test_methods_without_traits() {
+        let mut v = ArrayVector::<u32, 6>::new();
+        v.push(1);
+        v.extend_from_slice(&[2, 3, 4]);
+        v.insert(0, 0);
+        assert_eq!(v.remove(1), 1);
+        assert_eq!(v.swap_remove(0), 0);
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v, [4, 2]);
+
+        v.clear();
+        assert!(v.is_empty());
+        assert_eq!(v.pop(), None);
+    }
+}
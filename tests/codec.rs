@@ -0,0 +1,102 @@
+extern crate vector;
+
+
+use vector::{ByteReader, DecodeError, Vector};
+
+
+#[test]
+fn test_put() {
+    let mut v = Vector::new();
+    v.put_u8(0xab);
+    v.put_i8(-1);
+    v.put_u16(0x0102);
+    v.put_u16_le(0x0102);
+    v.put_i32(-2);
+    v.put_u64_le(1);
+    v.put_f32(1.0);
+    v.put_slice(b"end");
+
+    assert_eq!(v, &[
+        0xab, 0xff,
+        0x01, 0x02,
+        0x02, 0x01,
+        0xff, 0xff, 0xff, 0xfe,
+        1, 0, 0, 0, 0, 0, 0, 0,
+        0x3f, 0x80, 0x00, 0x00,
+        b'e', b'n', b'd',
+    ][..]);
+
+    let mut v = Vector::new();
+    v.put_u128_ne(7);
+    v.put_f64_ne(0.5);
+    assert_eq!(&v[..16], 7u128.to_ne_bytes());
+    assert_eq!(&v[16..], 0.5f64.to_ne_bytes());
+}
+
+#[test]
+fn test_round_trip() {
+    let mut v = Vector::new();
+    v.put_u16_le(u16::MAX);
+    v.put_u32(0xdead_beef);
+    v.put_i64_le(i64::MIN);
+    v.put_i128(-3);
+    v.put_u128_le(u128::MAX - 1);
+    v.put_f32_le(-0.25);
+    v.put_f64(std::f64::consts::PI);
+    v.put_i16_ne(-300);
+
+    let mut r = ByteReader::new(&v);
+    assert_eq!(r.get_u16_le(), Ok(u16::MAX));
+    assert_eq!(r.get_u32(), Ok(0xdead_beef));
+    assert_eq!(r.get_i64_le(), Ok(i64::MIN));
+    assert_eq!(r.get_i128(), Ok(-3));
+    assert_eq!(r.get_u128_le(), Ok(u128::MAX - 1));
+    assert_eq!(r.get_f32_le(), Ok(-0.25));
+    assert_eq!(r.get_f64(), Ok(std::f64::consts::PI));
+    assert_eq!(r.get_i16_ne(), Ok(-300));
+    assert!(r.is_empty());
+}
+
+#[test]
+fn test_varint() {
+    let values = [0, 1, 127, 128, 300, 16_383, 16_384, u32::MAX as u64, u64::MAX];
+    let mut v = Vector::new();
+    for &value in values.iter() {
+        v.put_varint(value);
+    }
+    assert_eq!(&v[..6], [0, 1, 0x7f, 0x80, 0x01, 0xac]);
+    assert_eq!(v.len(), 1 + 1 + 1 + 2 + 2 + 2 + 3 + 5 + 10);
+
+    let mut r = ByteReader::from(&v[..]);
+    for &value in values.iter() {
+        assert_eq!(r.get_varint(), Ok(value));
+    }
+    assert!(r.is_empty());
+
+    let mut r = ByteReader::new(&[0xff; 10]);
+    assert_eq!(r.get_varint(), Err(DecodeError::VarintOverflow));
+    let mut r = ByteReader::new(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02]);
+    assert_eq!(r.get_varint(), Err(DecodeError::VarintOverflow));
+    assert_eq!(r.remaining(), 10);
+}
+
+#[test]
+fn test_underflow() {
+    let mut r = ByteReader::new(&[1, 2, 3]);
+
+    assert_eq!(r.get_u32(), Err(DecodeError::UnexpectedEnd { needed: 4, remaining: 3 }));
+    assert_eq!(r.remaining(), 3);
+    assert_eq!(r.get_u16(), Ok(0x0102));
+    assert_eq!(r.get_slice(2), Err(DecodeError::UnexpectedEnd { needed: 2, remaining: 1 }));
+    assert_eq!(r.advance(1), Ok(()));
+    assert_eq!(r.get_u8(), Err(DecodeError::UnexpectedEnd { needed: 1, remaining: 0 }));
+
+    let mut r = ByteReader::new(&[0x80, 0x80]);
+    assert_eq!(r.get_varint(), Err(DecodeError::UnexpectedEnd { needed: 3, remaining: 2 }));
+    assert_eq!(r.as_slice(), [0x80, 0x80]);
+
+    assert_eq!(
+        DecodeError::UnexpectedEnd { needed: 4, remaining: 3 }.to_string(),
+        "unexpected end of input: needed 4 bytes but only 3 remain"
+    );
+}
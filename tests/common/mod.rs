@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+
+#[derive(Clone, Default)]
+pub struct Drops(Arc<AtomicUsize>);
+
+impl Drops {
+    pub fn new() -> Self {
+        Drops::default()
+    }
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+
+pub struct Tracked {
+    pub value: u32,
+    pub panic_on_clone: bool,
+    pub panic_on_drop: bool,
+    drops: Drops,
+}
+
+impl Tracked {
+    pub fn new(value: u32, drops: &Drops) -> Self {
+        Tracked {
+            value,
+            panic_on_clone: false,
+            panic_on_drop: false,
+            drops: drops.clone(),
+        }
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        if self.panic_on_clone {
+            panic!("clone panicked");
+        }
+        Tracked::new(self.value, &self.drops)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.0.fetch_add(1, Ordering::SeqCst);
+        if self.panic_on_drop {
+            panic!("drop panicked");
+        }
+    }
+}
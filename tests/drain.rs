@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate vector;
+
+
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use vector::Vector;
+
+
+#[test]
+fn test_drain_len() {
+    let mut v = (0..10).collect::<Vector<u32>>();
+    let mut drain = v.drain(2..8);
+
+    assert_eq!(drain.len(), 6);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.next_back(), Some(7));
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.size_hint(), (4, Some(4)));
+    assert_eq!(drain.as_slice(), [3, 4, 5, 6]);
+    drop(drain);
+
+    assert_eq!(v, [0, 1, 8, 9]);
+}
+
+#[test]
+fn test_drain_keep_rest() {
+    let mut v = (0..10).collect::<Vector<u32>>();
+    let mut drain = v.drain(2..8);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.next_back(), Some(7));
+    drain.keep_rest();
+    assert_eq!(v, [0, 1, 3, 4, 5, 6, 8, 9]);
+
+    let mut v = (0..4).collect::<Vector<u32>>();
+    v.drain(..).keep_rest();
+    assert_eq!(v, [0, 1, 2, 3]);
+
+    let mut v = vector![(), (), ()];
+    let mut drain = v.drain(1..);
+    drain.next();
+    drain.keep_rest();
+    assert_eq!(v.len(), 2);
+}
+
+#[test]
+fn test_drain_forget() {
+    let rc = Rc::new(());
+    let mut v = (0..6).map(|_| rc.clone()).collect::<Vector<_>>();
+
+    mem::forget(v.drain(2..4));
+    assert_eq!(v.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    unsafe {
+        v.set_len(6);
+    }
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_drain_panic_in_drop() {
+    struct PanicOnDrop {
+        id: u32,
+        _rc: Rc<()>,
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.id == 3 {
+                panic!("element panicked");
+            }
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut v = (0..8).map(|id| PanicOnDrop { id, _rc: rc.clone() }).collect::<Vector<_>>();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut drain = v.drain(1..6);
+        drain.next();
+    }));
+    assert!(result.is_err());
+    assert_eq!(v.iter().map(|x| x.id).collect::<Vec<_>>(), [0, 6, 7]);
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
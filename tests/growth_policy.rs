@@ -0,0 +1,73 @@
+extern crate vector;
+
+
+use vector::{Doubling, Exact, FixedIncrement, GrowthPolicy, OneAndAHalf, PageAligned, ShrinkOnPop, Vector};
+
+
+fn capacities<G: GrowthPolicy>(policy: G, n: usize) -> Vec<usize> {
+    let mut v = Vector::new().with_growth(policy);
+    let mut caps = Vec::new();
+
+    for i in 0..n {
+        v.push(i as u32);
+        if caps.last() != Some(&v.capacity()) {
+            caps.push(v.capacity());
+        }
+    }
+    caps
+}
+
+#[test]
+fn test_growth_policies() {
+    assert_eq!(capacities(Doubling, 20), [4, 8, 16, 32]);
+    assert_eq!(capacities(OneAndAHalf, 20), [4, 6, 9, 13, 19, 28]);
+    assert_eq!(capacities(FixedIncrement(5), 20), [5, 10, 15, 20]);
+    assert_eq!(capacities(Exact, 4), [1, 2, 3, 4]);
+    assert_eq!(capacities(PageAligned(64), 20), [16, 32]);
+
+    let mut v = Vector::new().with_growth(FixedIncrement(4));
+    v.extend(0..10u32);
+    assert_eq!(v.capacity(), 12);
+    v.reserve(7);
+    assert_eq!(v.capacity(), 20);
+    v.insert(0, 10);
+    assert_eq!(v.len(), 11);
+}
+
+#[test]
+fn test_shrink_to() {
+    let mut v = Vector::with_capacity(100);
+    v.extend(0..10u32);
+
+    v.shrink_to(50);
+    assert_eq!(v.capacity(), 50);
+    v.shrink_to(0);
+    assert_eq!(v.capacity(), 10);
+    v.shrink_to(20);
+    assert_eq!(v.capacity(), 10);
+    assert_eq!(v, (0..10).collect::<Vector<_>>());
+}
+
+#[test]
+fn test_shrink_on_pop() {
+    let mut v = Vector::with_capacity(64).with_growth(ShrinkOnPop(Doubling));
+    v.extend(0..64u32);
+
+    while v.len() > 17 {
+        v.pop();
+    }
+    assert_eq!(v.capacity(), 64);
+    v.pop();
+    assert_eq!(v.capacity(), 32);
+
+    v.truncate(2);
+    assert_eq!(v.capacity(), 16);
+    v.clear();
+    assert_eq!(v.capacity(), 8);
+    v.push(1);
+    assert_eq!(v, [1]);
+
+    let mut v = v.with_growth(Doubling);
+    v.pop();
+    assert_eq!(v.capacity(), 8);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "nightly")]
+
+extern crate vector;
+
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use vector::Vector;
+
+use common::{Drops, Tracked};
+
+
+#[test]
+fn test_reuses_allocation() {
+    let v: Vector<u32> = (0..64).collect();
+    let ptr = v.as_ptr();
+    let capacity = v.capacity();
+
+    let v: Vector<i32> = v.into_iter().map(|x| x as i32 * -2).filter(|x| x % 3 != 0).collect();
+    assert_eq!(v.as_ptr() as *const u32, ptr);
+    assert_eq!(v.capacity(), capacity);
+    assert_eq!(v.len(), 42);
+    assert_eq!(&v[..4], [-2, -4, -8, -10]);
+
+    let v: Vector<f32> = v.into_iter().enumerate().map(|(i, x)| (i as i32 + x) as f32).collect();
+    assert_eq!(v.as_ptr() as *const u32, ptr);
+    assert_eq!(&v[..2], [-2.0, -3.0]);
+}
+
+#[test]
+fn test_incompatible_layout() {
+    let v: Vector<u64> = (0..8).collect();
+    let v: Vector<u32> = v.into_iter().map(|x| x as u32).collect();
+    assert_eq!(v, &[0, 1, 2, 3, 4, 5, 6, 7][..]);
+
+    let v: Vector<()> = Vector::from(&[(); 4][..]).into_iter().collect();
+    assert_eq!(v.len(), 4);
+}
+
+#[test]
+fn test_shrinks_sparse_result() {
+    let v: Vector<u32> = (0..64).collect();
+    let v: Vector<u32> = v.into_iter().filter(|x| x % 8 == 0).collect();
+    assert_eq!(v, &[0, 8, 16, 24, 32, 40, 48, 56][..]);
+    assert_eq!(v.capacity(), 8);
+}
+
+#[test]
+fn test_drops() {
+    let drops = Drops::new();
+    let v: Vector<Tracked> = (0..10).map(|value| Tracked::new(value, &drops)).collect();
+
+    let v: Vector<Tracked> = v.into_iter().filter(|x| x.value % 2 == 0).take(3).collect();
+    assert_eq!(v.iter().map(|x| x.value).collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!(drops.get(), 7);
+    drop(v);
+    assert_eq!(drops.get(), 10);
+
+    let v: Vector<Tracked> = (0..6).map(|value| Tracked::new(value, &drops)).collect();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        v.into_iter()
+            .map(|x| if x.value == 3 { panic!("map panicked") } else { x })
+            .collect::<Vector<Tracked>>()
+    }));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 16);
+}
@@ -0,0 +1,96 @@
+extern crate vector;
+
+
+use vector::Vector;
+
+
+#[test]
+fn test_fmt_write() {
+    use std::fmt::Write;
+
+    let mut v = Vector::from("x = ");
+    write!(v, "{} + {}", 1, 2.5).unwrap();
+    v.write_char('!').unwrap();
+    assert_eq!(v, &b"x = 1 + 2.5!"[..]);
+}
+
+#[cfg(feature = "std")]
+mod std_io {
+    use std::io::{self, BufRead, IoSlice, Read, Seek, SeekFrom, Write};
+
+    use vector::{Vector, VectorCursor};
+
+
+    #[test]
+    fn test_write() {
+        let mut v = Vector::new();
+        v.write_all(b"hello").unwrap();
+        assert_eq!(v.write(b", ").unwrap(), 2);
+
+        let bufs = [IoSlice::new(b"wor"), IoSlice::new(b""), IoSlice::new(b"ld")];
+        assert_eq!(v.write_vectored(&bufs).unwrap(), 5);
+        write!(v, " {}", 42).unwrap();
+        v.flush().unwrap();
+
+        assert_eq!(v, &b"hello, world 42"[..]);
+        assert_eq!(io::copy(&mut &b"!"[..], &mut v).unwrap(), 1);
+        assert_eq!(v.last(), Some(&b'!'));
+    }
+
+    #[test]
+    fn test_write_fmt_with_both_traits() {
+        use std::fmt::Write as FmtWrite;
+
+        let mut v = Vector::new();
+        io::Write::write_fmt(&mut v, format_args!("{}-", 1)).unwrap();
+        FmtWrite::write_fmt(&mut v, format_args!("{}", 2)).unwrap();
+        v.write_char('!').unwrap();
+        assert_eq!(v, &b"1-2!"[..]);
+    }
+
+    #[test]
+    fn test_cursor_read() {
+        let mut cursor = VectorCursor::new(Vector::from("line one\nline two\nend"));
+
+        let mut buf = [0; 4];
+        cursor.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"line");
+        assert_eq!(cursor.position(), 4);
+
+        let mut line = String::new();
+        cursor.read_line(&mut line).unwrap();
+        assert_eq!(line, " one\n");
+
+        let lines: Vec<String> = cursor.by_ref().lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["line two", "end"]);
+        assert!(cursor.is_empty());
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert_eq!(cursor.read_exact(&mut buf).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        cursor.set_position(100);
+        assert_eq!(cursor.fill_buf().unwrap(), b"");
+        assert_eq!(cursor.into_inner().len(), 21);
+    }
+
+    #[test]
+    fn test_cursor_seek() {
+        let mut cursor = VectorCursor::from(Vector::from(&b"0123456789"[..]));
+
+        assert_eq!(cursor.seek(SeekFrom::End(-3)).unwrap(), 7);
+        assert_eq!(cursor.fill_buf().unwrap(), b"789");
+        cursor.consume(2);
+        assert_eq!(cursor.stream_position().unwrap(), 9);
+
+        assert_eq!(cursor.seek(SeekFrom::Current(-9)).unwrap(), 0);
+        assert!(cursor.seek(SeekFrom::Current(-1)).is_err());
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.seek(SeekFrom::Start(20)).unwrap(), 20);
+
+        cursor.rewind().unwrap();
+        let mut rest = Vec::new();
+        cursor.get_mut().extend_from_slice(b"ab");
+        cursor.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"0123456789ab");
+        assert_eq!(cursor.get_ref().len(), 12);
+    }
+}
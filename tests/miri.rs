@@ -0,0 +1,288 @@
+#[macro_use]
+extern crate vector;
+extern crate collection_traits;
+
+mod common;
+
+use std::panic::{self, AssertUnwindSafe};
+
+use vector::{ArrayVector, SmallVector, Vector, VectorDeque};
+use collection_traits::*;
+
+use common::{Drops, Tracked};
+
+
+fn tracked(n: u32, drops: &Drops) -> Vector<Tracked> {
+    (0..n).map(|i| Tracked::new(i, drops)).collect()
+}
+
+fn values(v: &[Tracked]) -> Vec<u32> {
+    v.iter().map(|x| x.value).collect()
+}
+
+
+#[test]
+fn test_zero_sized() {
+    let mut v = Vector::new();
+    for _ in 0..4 {
+        v.push(());
+    }
+    v.insert(2, ());
+    assert_eq!(v.remove(0), ());
+    assert_eq!(v.swap_remove(0), ());
+    assert_eq!(v.len(), 3);
+
+    let mut other = v.split_off(1);
+    v.append(&mut other);
+    v.extend_from_within(..);
+    assert_eq!(v.len(), 6);
+
+    assert_eq!(v.drain(1..3).count(), 2);
+    assert_eq!(v.clone().len(), 4);
+
+    let mut iter = v.into_iter();
+    assert_eq!(iter.next(), Some(()));
+    assert_eq!(iter.next_back(), Some(()));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.as_slice().len(), 2);
+}
+
+#[test]
+fn test_empty() {
+    let mut v = Vector::<Box<u32>>::new();
+    assert!(v.pop().is_none());
+    assert_eq!(v.drain(..).count(), 0);
+    assert!(v.split_off(0).is_empty());
+    v.dedup_by(|a, b| a == b);
+    v.retain(|_| true);
+    v.truncate(0);
+    v.shrink_to_fit();
+    assert!(v.as_slice().is_empty());
+    assert!(v.clone().into_iter().next().is_none());
+    assert!(v.into_boxed_slice().is_empty());
+}
+
+#[test]
+fn test_capacity_boundaries() {
+    let mut v = Vector::<Box<u32>>::with_capacity(2);
+    v.push(Box::new(0));
+    v.push(Box::new(1));
+    assert_eq!(v.capacity(), 2);
+    v.insert(1, Box::new(2));
+    assert!(v.capacity() > 2);
+
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 3);
+    assert!(v.push_within_capacity(Box::new(3)).is_err());
+
+    v.reserve_exact(1);
+    v.push(Box::new(3));
+    assert_eq!(v.len(), v.capacity());
+
+    let boxed = v.clone().into_boxed_slice();
+    assert_eq!(boxed.len(), 4);
+    let std: Vec<Box<u32>> = v.into();
+    let back = Vector::from(std);
+    assert_eq!(*back[3], 3);
+
+    let mut v = vector![Box::new(1)];
+    v.clear();
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 0);
+}
+
+#[test]
+fn test_drop_counts() {
+    let drops = Drops::new();
+
+    let mut v = tracked(10, &drops);
+    drop(v.remove(3));
+    drop(v.swap_remove(0));
+    v.truncate(6);
+    assert_eq!(drops.get(), 4);
+
+    let mut tail = v.split_off(4);
+    v.append(&mut tail);
+    v.retain(|x| x.value % 2 == 0);
+    v.dedup_by_key(|x| x.value / 4);
+    assert_eq!(values(&v), [2, 4]);
+    assert_eq!(drops.get(), 8);
+
+    let mut v = tracked(8, &drops);
+    drop(v.drain(2..6).next());
+    drop(v.splice(1..2, Some(Tracked::new(10, &drops))));
+    assert_eq!(v.extract_if(.., |x| x.value == 7).count(), 1);
+    assert_eq!(values(&v), [0, 10, 6]);
+
+    let mut iter = v.into_iter();
+    drop(iter.next());
+    drop(iter);
+    assert_eq!(drops.get(), 8 + 9);
+}
+
+#[test]
+fn test_panicking_clone() {
+    let drops = Drops::new();
+
+    let mut v = tracked(4, &drops);
+    v[2].panic_on_clone = true;
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| v.clone())).is_err());
+    assert_eq!(drops.get(), 2);
+
+    let mut target = tracked(1, &drops);
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| target.clone_from(&v))).is_err());
+    assert_eq!(target.len(), 2);
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| v.extend_from_within(..))).is_err());
+    assert_eq!(v.len(), 6);
+
+    let value = v.remove(2);
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| v.resize(8, value))).is_err());
+    assert_eq!(v.len(), 5);
+
+    drop(target);
+    drop(v);
+    assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn test_panicking_drop() {
+    let drops = Drops::new();
+
+    let mut v = tracked(6, &drops);
+    v[3].panic_on_drop = true;
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| v.truncate(2))).is_err());
+    assert_eq!(v.len(), 2);
+    assert_eq!(drops.get(), 4);
+
+    let mut v = tracked(6, &drops);
+    v[2].panic_on_drop = true;
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(v.drain(1..4)))).is_err());
+    assert_eq!(values(&v), [0, 4, 5]);
+
+    let mut v = tracked(4, &drops);
+    v[1].panic_on_drop = true;
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| drop(v.into_iter()))).is_err());
+    assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn test_into_iter_clone() {
+    let drops = Drops::new();
+
+    let mut iter = tracked(4, &drops).into_iter();
+    iter.next();
+    let clone = iter.clone();
+    assert_eq!(values(clone.as_slice()), [1, 2, 3]);
+    drop(iter);
+    drop(clone);
+    assert_eq!(drops.get(), 1 + 3 + 3);
+}
+
+#[test]
+fn test_into_iter_into_vector() {
+    let drops = Drops::new();
+
+    let mut iter = tracked(6, &drops).into_iter();
+    iter.next();
+    iter.next_back();
+    let ptr = iter.as_slice().as_ptr();
+
+    let mut v = iter.into_vector();
+    assert_eq!(values(&v), [1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 6);
+    assert_eq!(v.as_ptr(), unsafe { ptr.sub(1) });
+    v.push(Tracked::new(9, &drops));
+    assert_eq!(drops.get(), 2);
+    drop(v);
+    assert_eq!(drops.get(), 7);
+
+    let v = vector![(); 3].into_iter().into_vector();
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn test_into_iter_advance_by() {
+    let drops = Drops::new();
+
+    let mut iter = tracked(10, &drops).into_iter();
+    assert_eq!(iter.advance_by(3), Ok(()));
+    assert_eq!(drops.get(), 3);
+    assert_eq!(iter.nth(2).map(|x| x.value), Some(5));
+    assert_eq!(drops.get(), 6);
+    assert_eq!(iter.advance_by(10).unwrap_err().get(), 6);
+    assert_eq!(drops.get(), 10);
+    assert!(iter.nth(1).is_none());
+
+    let mut iter = tracked(4, &drops).into_iter();
+    iter.as_mut_slice()[1].panic_on_drop = true;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| iter.advance_by(3)));
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 13);
+    assert_eq!(values(iter.as_slice()), [3]);
+    drop(iter);
+    assert_eq!(drops.get(), 14);
+
+    let mut iter = vector![(); 5].into_iter();
+    assert_eq!(iter.nth(3), Some(()));
+    assert_eq!(iter.len(), 1);
+}
+
+#[test]
+fn test_into_iter_forget_remaining_elements() {
+    let drops = Drops::new();
+
+    let mut iter = tracked(3, &drops).into_iter();
+    let first = iter.next().unwrap();
+    let rest: Vec<Tracked> = iter.as_slice().iter().map(|x| unsafe { std::ptr::read(x) }).collect();
+    iter.forget_remaining_elements();
+    assert_eq!(iter.len(), 0);
+    drop(iter);
+    assert_eq!(drops.get(), 0);
+
+    drop(first);
+    drop(rest);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_vector_deque() {
+    let drops = Drops::new();
+    let mut d = VectorDeque::with_capacity(4);
+
+    for i in 0..3 {
+        d.push_back(Tracked::new(i, &drops));
+    }
+    d.pop_front();
+    d.push_back(Tracked::new(3, &drops));
+    d.push_back(Tracked::new(4, &drops));
+    d.push_front(Tracked::new(5, &drops));
+    d.insert(2, Tracked::new(6, &drops));
+    drop(d.remove(1));
+
+    let v = Vector::from(d);
+    assert_eq!(values(&v), [5, 6, 2, 3, 4]);
+    drop(v);
+    assert_eq!(drops.get(), 7);
+}
+
+#[test]
+fn test_small_and_array_vector() {
+    let drops = Drops::new();
+
+    let mut s = SmallVector::<Tracked, 2>::new();
+    s.extend((0..4).map(|i| Tracked::new(i, &drops)));
+    s.truncate(2);
+    s.shrink_to_fit();
+    assert!(!s.spilled());
+    s.retain(|x| x.value == 1);
+    assert_eq!(values(&s.into_vector()), [1]);
+
+    let mut a = ArrayVector::<Tracked, 3>::new();
+    a.extend((0..3).map(|i| Tracked::new(i, &drops)));
+    assert!(a.try_push(Tracked::new(3, &drops)).is_err());
+    drop(a.drain(..1));
+    drop(a.into_iter().next_back());
+    assert_eq!(drops.get(), 4 + 4);
+}
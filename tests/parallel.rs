@@ -0,0 +1,92 @@
+#![cfg(feature = "rayon")]
+
+extern crate rayon;
+extern crate vector;
+
+mod common;
+
+use rayon::prelude::*;
+
+use vector::{Global, ShrinkOnPop, Vector};
+
+use common::{Drops, Tracked};
+
+
+#[test]
+fn test_par_iter() {
+    let mut v: Vector<u64> = (0..10_000).collect();
+
+    assert_eq!(v.par_iter().sum::<u64>(), 49_995_000);
+    v.par_iter_mut().for_each(|x| *x *= 2);
+    assert_eq!(v[4_999], 9_998);
+
+    let doubled: Vec<u64> = v.clone().into_par_iter().map(|x| x / 2).collect();
+    assert_eq!(doubled, (0..10_000).collect::<Vec<_>>());
+    assert_eq!(v.into_par_iter().rev().take(2).collect::<Vec<_>>(), [19_998, 19_996]);
+}
+
+#[test]
+fn test_collect() {
+    let v: Vector<usize> = (0..10_000usize).into_par_iter().map(|x| x * 3).collect();
+    assert_eq!(v.len(), 10_000);
+    assert!(v.iter().enumerate().all(|(i, &x)| x == i * 3));
+    assert_eq!(v.capacity(), 10_000);
+
+    let v: Vector<usize> = (0..10_000usize).into_par_iter().filter(|x| x % 7 == 0).collect();
+    assert_eq!(v, (0..10_000).filter(|x| x % 7 == 0).collect::<Vector<_>>());
+
+    let v: Vector<u8, Global, ShrinkOnPop> = (0..10u8).into_par_iter().collect();
+    assert_eq!(v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let v: Vector<()> = (0..1_000).into_par_iter().map(|_| ()).collect();
+    assert_eq!(v.len(), 1_000);
+}
+
+#[test]
+fn test_par_extend() {
+    let mut v: Vector<i32> = Vector::new();
+    v.push(-1);
+    v.par_extend((0..1_000).into_par_iter());
+    v.par_extend(vec![1, 2, 3].par_iter());
+    v.par_extend((0..100).into_par_iter().filter(|x| x % 10 == 0));
+
+    assert_eq!(v.len(), 1 + 1_000 + 3 + 10);
+    assert_eq!(v[0], -1);
+    assert_eq!(v[1_000], 999);
+    assert_eq!(&v[1_001..1_004], [1, 2, 3]);
+    assert_eq!(&v[1_004..], [0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
+}
+
+#[test]
+fn test_par_drain() {
+    let mut v: Vector<u32> = (0..100).collect();
+
+    let drained: Vec<u32> = v.par_drain(10..90).collect();
+    assert_eq!(drained, (10..90).collect::<Vec<_>>());
+    assert_eq!(v, (0..10).chain(90..100).collect::<Vector<_>>());
+
+    let _ = v.par_drain(5..5);
+    assert_eq!(v.len(), 20);
+    drop(v.par_drain(..5));
+    assert_eq!(v, (5..10).chain(90..100).collect::<Vector<_>>());
+
+    assert_eq!(v.par_drain(..).sum::<u32>(), 35 + 945);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_drop_counts() {
+    let drops = Drops::new();
+    let counted = |n| (0..n).map(|_| Tracked::new(0, &drops)).collect::<Vector<_>>();
+
+    let mut v = counted(1_000);
+    let taken = v.par_drain(100..200).take(10).count();
+    assert_eq!(taken, 10);
+    assert_eq!(drops.get(), 100);
+    assert_eq!(v.len(), 900);
+
+    let v2: Vector<Tracked> = v.into_par_iter().skip(450).collect();
+    assert_eq!(drops.get(), 550);
+    drop(v2);
+    assert_eq!(drops.get(), 1_000);
+}
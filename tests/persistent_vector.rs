@@ -0,0 +1,131 @@
+extern crate vector;
+extern crate collection_traits;
+
+
+use collection_traits::*;
+
+use vector::{PersistentVector, Vector};
+
+
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) as usize) % bound
+    }
+}
+
+fn check(p: &PersistentVector<usize>, expected: &[usize]) {
+    assert_eq!(p.len(), expected.len());
+    for (i, x) in expected.iter().enumerate() {
+        assert_eq!(p.get(i), Some(x));
+    }
+    assert_eq!(p.get(expected.len()), None);
+    assert!(p.iter().eq(expected.iter()));
+    assert!(p.iter().rev().eq(expected.iter().rev()));
+}
+
+
+#[test]
+fn test_push_back_get() {
+    let mut p = PersistentVector::new();
+    let mut expected = Vec::new();
+
+    for i in 0..40_000 {
+        p.push_back(i);
+        expected.push(i);
+    }
+    check(&p, &expected);
+    assert_eq!(p[33_000], 33_000);
+    assert_eq!(p.front(), Some(&0));
+    assert_eq!(p.back(), Some(&39_999));
+    assert_eq!(Collection::len(&p), 40_000);
+}
+
+#[test]
+fn test_structural_sharing() {
+    let mut p: PersistentVector<usize> = (0..1000).collect();
+    let snapshot = p.clone();
+    assert!(p.ptr_eq(&snapshot));
+
+    p.set(500, 0);
+    p.push_back(1000);
+    *p.get_mut(0).unwrap() = 7;
+    let updated = p.update(999, 42);
+
+    assert!(!p.ptr_eq(&snapshot));
+    assert_eq!(snapshot[500], 500);
+    assert_eq!(snapshot.len(), 1000);
+    assert_eq!((p[0], p[500], p[999], p.len()), (7, 0, 999, 1001));
+    assert_eq!(updated[999], 42);
+    assert_ne!(updated, p);
+}
+
+#[test]
+fn test_split_at() {
+    let expected: Vec<usize> = (0..5000).collect();
+    let p: PersistentVector<usize> = expected.iter().cloned().collect();
+
+    for &index in [0, 1, 31, 32, 33, 1023, 1024, 1025, 2500, 4999, 5000].iter() {
+        let (left, right) = p.split_at(index);
+        check(&left, &expected[..index]);
+        check(&right, &expected[index..]);
+
+        let mut left = left;
+        left.push_back(9);
+        assert_eq!(left[index], 9);
+        assert_eq!(left.concat(&right).len(), 5001);
+    }
+}
+
+#[test]
+fn test_concat() {
+    let mut rng = Lcg(7);
+    let mut p = PersistentVector::new();
+    let mut expected = Vec::new();
+
+    for round in 0..200 {
+        let len = rng.next(if round % 10 == 0 { 3000 } else { 40 });
+        let piece: PersistentVector<usize> = (expected.len()..expected.len() + len).collect();
+        expected.extend(piece.iter().cloned());
+
+        p = if round % 3 == 0 {
+            let (left, right) = p.split_at(rng.next(p.len() + 1));
+            left.concat(&right).concat(&piece)
+        } else {
+            p.concat(&piece)
+        };
+    }
+    check(&p, &expected);
+
+    p.extend(0..100);
+    expected.extend(0..100);
+    check(&p, &expected);
+
+    let mut q = PersistentVector::new();
+    let mut expected = Vec::new();
+    for i in 0..500 {
+        q = PersistentVector::from(Vector::from(&[i][..])).concat(&q);
+        expected.insert(0, i);
+    }
+    check(&q, &expected);
+}
+
+#[test]
+fn test_conversions() {
+    let vector: Vector<String> = (0..100).map(|i| i.to_string()).collect();
+    let p = PersistentVector::from(vector.clone());
+    assert_eq!(p[64], "64");
+
+    let snapshot = p.clone();
+    assert_eq!(Vector::from(p), vector);
+    assert_eq!(snapshot.into_iter().next_back().as_deref(), Some("99"));
+
+    let mut p: PersistentVector<u8> = PersistentVector::default();
+    assert!(p.iter().next().is_none());
+    p.extend(vec![1, 2, 3]);
+    assert_eq!(format!("{:?}", p), "[1, 2, 3]");
+    p.clear();
+    assert!(p.is_empty());
+}
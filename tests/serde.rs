@@ -0,0 +1,102 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate vector;
+#[macro_use]
+extern crate serde;
+extern crate bincode;
+extern crate serde_json;
+extern crate serde_test;
+
+
+use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+use vector::{ShrinkOnPop, Vector};
+
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Packet {
+    id: u32,
+    #[serde(with = "vector::serde_bytes")]
+    payload: Vector<u8>,
+}
+
+
+#[test]
+fn test_tokens() {
+    let v: Vector<u32> = vector![1, 2, 3];
+    assert_tokens(&v, &[
+        Token::Seq { len: Some(3) },
+        Token::U32(1),
+        Token::U32(2),
+        Token::U32(3),
+        Token::SeqEnd,
+    ]);
+    assert_tokens(&Vector::<u32>::new(), &[Token::Seq { len: Some(0) }, Token::SeqEnd]);
+
+    let v: Vector<u32, _, ShrinkOnPop> = Vector::new().with_growth(ShrinkOnPop::default());
+    assert_de_tokens(&v, &[Token::Seq { len: None }, Token::SeqEnd]);
+}
+
+#[test]
+fn test_formats() {
+    let v: Vector<Vector<i64>> = vector![vector![1, -2], vector![], vector![3]];
+
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[[1,-2],[],[3]]");
+    assert_eq!(serde_json::from_str::<Vector<Vector<i64>>>(&json).unwrap(), v);
+
+    let encoded = bincode::serialize(&v).unwrap();
+    assert_eq!(bincode::deserialize::<Vector<Vector<i64>>>(&encoded).unwrap(), v);
+    assert_eq!(encoded, bincode::serialize(&vec![vec![1i64, -2], vec![], vec![3]]).unwrap());
+}
+
+#[test]
+fn test_untrusted_size_hint() {
+    let mut encoded = bincode::serialize(&(u64::MAX, 1u8)).unwrap();
+    assert!(bincode::deserialize::<Vector<u8>>(&encoded).is_err());
+
+    encoded.truncate(8);
+    assert!(bincode::deserialize::<Vector<u64>>(&encoded).is_err());
+}
+
+#[test]
+fn test_deserialize_in_place() {
+    let mut v: Vector<u32> = Vector::with_capacity(16);
+    v.extend([7, 8, 9]);
+
+    let mut de = serde_json::Deserializer::from_str("[1,2]");
+    serde::Deserialize::deserialize_in_place(&mut de, &mut v).unwrap();
+    assert_eq!(v, [1, 2]);
+    assert_eq!(v.capacity(), 16);
+}
+
+#[test]
+fn test_serde_bytes() {
+    let packet = Packet { id: 7, payload: Vector::from(&b"\x00\xffab"[..]) };
+
+    let encoded = bincode::serialize(&packet).unwrap();
+    assert_eq!(encoded, [7, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0x00, 0xff, b'a', b'b']);
+    assert_eq!(bincode::deserialize::<Packet>(&encoded).unwrap(), packet);
+
+    let json = serde_json::to_string(&packet).unwrap();
+    assert_eq!(json, r#"{"id":7,"payload":[0,255,97,98]}"#);
+    assert_eq!(serde_json::from_str::<Packet>(&json).unwrap(), packet);
+
+    assert_de_tokens(&Packet { id: 1, payload: Vector::from("hi") }, &[
+        Token::Struct { name: "Packet", len: 2 },
+        Token::Str("id"),
+        Token::U32(1),
+        Token::Str("payload"),
+        Token::Bytes(b"hi"),
+        Token::StructEnd,
+    ]);
+    assert_de_tokens(&Packet { id: 1, payload: Vector::from("hi") }, &[
+        Token::Struct { name: "Packet", len: 2 },
+        Token::Str("id"),
+        Token::U32(1),
+        Token::Str("payload"),
+        Token::String("hi"),
+        Token::StructEnd,
+    ]);
+}
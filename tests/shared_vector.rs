@@ -0,0 +1,96 @@
+extern crate vector;
+
+mod common;
+
+use std::thread;
+
+use vector::{ArcVector, RcVector, SharedVector, Vector};
+
+use common::{Drops, Tracked};
+
+
+#[test]
+fn test_clone_and_make_mut() {
+    let mut a: ArcVector<u32> = (0..100).collect();
+    let b = a.clone();
+    assert!(SharedVector::ptr_eq(&a, &b));
+    assert_eq!(SharedVector::strong_count(&a), 2);
+    assert!(SharedVector::get_mut(&mut a).is_none());
+
+    SharedVector::make_mut(&mut a)[0] = 42;
+    assert!(!SharedVector::ptr_eq(&a, &b));
+    assert_eq!((a[0], b[0]), (42, 0));
+    assert_eq!(SharedVector::strong_count(&b), 1);
+
+    let ptr = a.as_ptr();
+    SharedVector::make_mut(&mut a)[1] = 43;
+    SharedVector::get_mut(&mut a).unwrap()[2] = 44;
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(&a[..4], [42, 43, 44, 3]);
+    assert_eq!(a.len(), 100);
+}
+
+#[test]
+fn test_try_unwrap() {
+    let a: ArcVector<u64> = (0..10).collect();
+    let ptr = a.as_ptr();
+    let b = a.clone();
+
+    let a = SharedVector::try_unwrap(a).unwrap_err();
+    drop(b);
+    let mut vector = SharedVector::try_unwrap(a).unwrap();
+    assert_eq!(vector.as_ptr(), ptr);
+    assert!(vector.capacity() > 10);
+    vector.push(10);
+    assert_eq!(vector, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10][..]);
+
+    let mut vector = Vector::with_capacity(64);
+    vector.extend_from_slice(&[1u64, 2, 3]);
+    let ptr = vector.as_ptr();
+    let shared = RcVector::from(vector);
+    assert_eq!(shared.as_ptr(), ptr);
+    let vector = SharedVector::into_vector(shared);
+    assert_eq!((vector.as_ptr(), vector.capacity()), (ptr, 64));
+
+    let shared: RcVector<u8> = RcVector::from(&b"bytes"[..]);
+    let other = shared.clone();
+    assert_eq!(SharedVector::into_vector(shared), &b"bytes"[..]);
+    assert_eq!(SharedVector::try_unwrap(other).unwrap().len(), 5);
+
+    let zst: RcVector<()> = Vector::from(&[(); 3][..]).into_iter().collect();
+    assert_eq!(SharedVector::try_unwrap(zst).unwrap().len(), 3);
+}
+
+#[test]
+fn test_drops() {
+    let drops = Drops::new();
+
+    let a: RcVector<Tracked> = (0..5).map(|_| Tracked::new(0, &drops)).collect();
+    let mut b = a.clone();
+    SharedVector::make_mut(&mut b);
+    drop(a);
+    assert_eq!(drops.get(), 5);
+    drop(b);
+    assert_eq!(drops.get(), 10);
+
+    let a: RcVector<Tracked> = Vector::from(&[Tracked::new(0, &drops)][..]).into_iter().collect();
+    drop(SharedVector::try_unwrap(a).ok().unwrap());
+    assert_eq!(drops.get(), 12);
+}
+
+#[test]
+fn test_threads() {
+    let shared: ArcVector<String> = (0..8).map(|i| i.to_string()).collect();
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let shared = shared.clone();
+            thread::spawn(move || shared[i * 2].clone())
+        })
+        .collect();
+    let results: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(results, ["0", "2", "4", "6"]);
+    assert_eq!(SharedVector::strong_count(&shared), 1);
+    assert_eq!(format!("{:?}", ArcVector::<u8>::default()), "[]");
+}
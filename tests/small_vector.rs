@@ -0,0 +1,125 @@
+extern crate vector;
+extern crate collection_traits;
+
+
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use vector::{SmallVector, Vector};
+use collection_traits::*;
+
+
+#[test]
+fn test_inline_and_spill() {
+    let mut v = SmallVector::<u32, 4>::new();
+    assert_eq!(v.inline_size(), 4);
+    assert_eq!(v.capacity(), 4);
+
+    v.extend(0..4);
+    assert!(!v.spilled());
+    v.push(4);
+    assert!(v.spilled());
+    assert_eq!(v, [0, 1, 2, 3, 4]);
+
+    v.insert(0, 10);
+    assert_eq!(v.remove(1), 0);
+    assert_eq!(v.pop(), Some(4));
+    assert_eq!(v, [10, 1, 2, 3]);
+
+    v.shrink_to_fit();
+    assert!(!v.spilled());
+    assert_eq!(v, [10, 1, 2, 3]);
+
+    let zst = (0..100).map(|_| ()).collect::<SmallVector<(), 2>>();
+    assert!(!zst.spilled());
+    assert_eq!(zst.len(), 100);
+}
+
+#[test]
+fn test_methods() {
+    let mut v = (0..10).collect::<SmallVector<u32, 8>>();
+
+    v.retain(|&x| x % 2 == 0);
+    assert_eq!(v, [0, 2, 4, 6, 8]);
+
+    assert_eq!(v.drain(1..3).collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(v, [0, 6, 8]);
+
+    let tail = v.split_off(1);
+    assert_eq!(tail, [6, 8]);
+    assert_eq!(v, [0]);
+
+    v.resize(3, 7);
+    assert_eq!(v, [0, 7, 7]);
+    v.truncate(1);
+    assert_eq!(v, [0]);
+
+    assert_eq!(v.clone(), v);
+    assert_eq!(v.into_iter().rev().collect::<Vec<_>>(), [0]);
+}
+
+#[test]
+fn test_into_vector() {
+    let spilled = (0..10).collect::<SmallVector<u32, 2>>();
+    let ptr = spilled.as_ptr();
+    let vector = spilled.into_vector();
+    assert_eq!(vector.as_ptr(), ptr);
+    assert_eq!(vector, (0..10).collect::<Vector<_>>());
+
+    let inline = (0..2).collect::<SmallVector<u32, 2>>();
+    assert_eq!(Vector::from(inline), [0, 1]);
+
+    let back = SmallVector::<u32, 2>::from(vector);
+    assert!(back.spilled());
+    assert_eq!(back.len(), 10);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+
+    let mut v = SmallVector::<Rc<()>, 2>::new();
+    v.push(rc.clone());
+    v.push(rc.clone());
+    v.push(rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let mut iter = v.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_inline_drain_panic_in_drop() {
+    struct PanicOnDrop {
+        id: u32,
+        _rc: Rc<()>,
+    }
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.id == 2 {
+                panic!("element panicked");
+            }
+        }
+    }
+
+    let rc = Rc::new(());
+    let mut v = (0..5).map(|id| PanicOnDrop { id, _rc: rc.clone() }).collect::<SmallVector<_, 8>>();
+    assert!(!v.spilled());
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut drain = v.drain(1..4);
+        assert_eq!(drain.next_back().map(|x| x.id), Some(3));
+    }));
+    assert!(result.is_err());
+    assert!(!v.spilled());
+    assert_eq!(v.iter().map(|x| x.id).collect::<Vec<_>>(), [0, 4]);
+    assert_eq!(Rc::strong_count(&rc), 3);
+
+    v.push(PanicOnDrop { id: 5, _rc: rc.clone() });
+    assert_eq!(v.iter().map(|x| x.id).collect::<Vec<_>>(), [0, 4, 5]);
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
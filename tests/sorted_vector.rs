@@ -0,0 +1,120 @@
+extern crate vector;
+extern crate collection_traits;
+
+
+use collection_traits::*;
+
+use vector::{SortedVector, SortedVectorMap, Vector};
+
+
+#[test]
+fn test_insert_remove() {
+    let mut s = SortedVector::new();
+    assert_eq!(s.insert(5), 0);
+    assert_eq!(s.insert(1), 0);
+    assert_eq!(s.insert(3), 1);
+    assert_eq!(s.insert(3), 2);
+    assert_eq!(s.insert_unique(3), Err(3));
+    assert_eq!(s.insert_unique(4), Ok(3));
+    assert_eq!(s, SortedVector::from(Vector::from(&[5, 4, 3, 3, 1][..])));
+    assert_eq!(&*s, [1, 3, 3, 4, 5]);
+
+    assert!(s.contains(&4));
+    assert_eq!(s.index_of(&3), Some(1));
+    assert_eq!(s.remove(&3), Some(3));
+    assert_eq!(s.remove(&2), None);
+    assert_eq!(RemoveMut::remove(&mut s, &3), Some(3));
+    assert!(!s.contains(&3));
+    assert_eq!(Collection::len(&s), 3);
+
+    let mut strings: SortedVector<String> = ["b", "a", "c"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(strings.remove("a"), Some("a".to_string()));
+    assert_eq!(strings.pop_first().as_deref(), Some("b"));
+    assert_eq!(strings.pop_last().as_deref(), Some("c"));
+}
+
+#[test]
+fn test_range_rank_select() {
+    let s: SortedVector<u32> = vec![10, 40, 20, 30, 20, 50].into_iter().collect();
+
+    assert_eq!(s.range(20..40), [20, 20, 30]);
+    assert_eq!(s.range(20..=40), [20, 20, 30, 40]);
+    assert_eq!(s.range(..25), [10, 20, 20]);
+    assert_eq!(s.range(45..), [50]);
+    assert!(s.range(31..40).is_empty());
+    assert_eq!(s.range(..), &s[..]);
+
+    assert_eq!(s.rank(&5), 0);
+    assert_eq!(s.rank(&20), 1);
+    assert_eq!(s.rank(&21), 3);
+    assert_eq!(s.rank(&99), 6);
+    assert_eq!(s.select(3), Some(&30));
+    assert_eq!(s.select(6), None);
+}
+
+#[test]
+fn test_extend() {
+    let mut s: SortedVector<u32> = (0..10).map(|x| x * 3).collect();
+    s.extend(vec![7, 1, 27, 100, 0]);
+    s.extend(&[2, 2]);
+    assert_eq!(&*s, [0, 0, 1, 2, 2, 3, 6, 7, 9, 12, 15, 18, 21, 24, 27, 27, 100]);
+    assert!(s.windows(2).all(|w| w[0] <= w[1]));
+
+    s.dedup();
+    assert_eq!(s.len(), 14);
+}
+
+#[test]
+fn test_set_operations() {
+    let a: SortedVector<u32> = vec![1, 2, 3, 5, 8].into_iter().collect();
+    let b: SortedVector<u32> = vec![2, 3, 4, 8, 9].into_iter().collect();
+
+    assert_eq!(&*a.union(&b), [1, 2, 3, 4, 5, 8, 9]);
+    assert_eq!(&*a.intersection(&b), [2, 3, 8]);
+    assert_eq!(&*a.difference(&b), [1, 5]);
+    assert_eq!(&*b.difference(&a), [4, 9]);
+    assert_eq!(&*a.symmetric_difference(&b), [1, 4, 5, 9]);
+    assert!(a.intersection(&SortedVector::new()).is_empty());
+    assert_eq!(a.union(&SortedVector::new()), a);
+}
+
+#[test]
+fn test_map() {
+    let mut m = SortedVectorMap::new();
+    assert_eq!(m.insert("b", 2), None);
+    assert_eq!(m.insert("a", 1), None);
+    assert_eq!(InsertMut::insert(&mut m, "d", 4), None);
+    assert_eq!(m.insert("b", 20), Some(2));
+
+    assert_eq!(m.get("b"), Some(&20));
+    assert_eq!(m["a"], 1);
+    assert!(!m.contains_key("c"));
+    *m.get_mut("a").unwrap() += 10;
+    assert_eq!(m.get_key_value("a"), Some((&"a", &11)));
+
+    assert_eq!(m.keys().cloned().collect::<Vec<_>>(), ["a", "b", "d"]);
+    assert_eq!(m.rank("c"), 2);
+    assert_eq!(m.range("b"..), [("b", 20), ("d", 4)]);
+    assert_eq!(m.select(2), Some((&"d", &4)));
+    assert_eq!(m.last_key_value(), Some((&"d", &4)));
+
+    for (_, v) in m.iter_mut() {
+        *v *= 2;
+    }
+    assert_eq!(m.values().sum::<i32>(), 70);
+    assert_eq!(m.remove("b"), Some(40));
+    assert_eq!(m.remove_entry("a"), Some(("a", 22)));
+    assert_eq!(format!("{:?}", m), "{\"d\": 8}");
+}
+
+#[test]
+fn test_map_extend_last_wins() {
+    let mut m: SortedVectorMap<u32, char> = vec![(3, 'a'), (1, 'b'), (3, 'c')].into_iter().collect();
+    assert_eq!(m.as_slice(), [(1, 'b'), (3, 'c')]);
+
+    m.extend(vec![(2, 'd'), (1, 'e'), (2, 'f'), (4, 'g')]);
+    assert_eq!(m.as_slice(), [(1, 'e'), (2, 'f'), (3, 'c'), (4, 'g')]);
+
+    m.retain(|&k, _| k % 2 == 0);
+    assert_eq!(Vector::from(m), &[(2, 'f'), (4, 'g')][..]);
+}
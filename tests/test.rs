@@ -4,7 +4,11 @@ extern crate zero;
 extern crate collection_traits;
 
 
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::ops::AddAssign;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 use vector::Vector;
 use zero::Zero;
@@ -87,3 +91,158 @@ fn test_iter() {
     let out = sum(&a);
     assert_eq!(out, 496);
 }
+
+#[test]
+fn test_drain() {
+    let mut a: Vector<usize> = (0..SIZE).collect();
+    let drained: Vector<usize> = a.drain(4..=7).collect();
+    assert_eq!(drained, [4, 5, 6, 7]);
+    assert_eq!(a.len(), SIZE - 4);
+    assert_eq!(a[4], 8);
+
+    let rest: Vector<usize> = a.drain(..).collect();
+    assert_eq!(rest.len(), SIZE - 4);
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_zero_sized() {
+    let mut a = Vector::new();
+    for _ in 0..SIZE {
+        a.push(());
+    }
+    assert_eq!(a.len(), SIZE);
+    assert_eq!(a.capacity(), usize::MAX);
+    assert_eq!(a.into_iter().count(), SIZE);
+}
+
+#[test]
+fn test_clone_deep() {
+    let mut a = Vector::new();
+    for i in 0..SIZE {
+        a.push(i.to_string());
+    }
+    let b = a.clone();
+    drop(a);
+    for i in 0..SIZE {
+        assert_eq!(b[i], i.to_string());
+    }
+
+    let mut c = vector![String::from("x"); 64];
+    let capacity = c.capacity();
+    c.clone_from(&b);
+    assert_eq!(c, b);
+    assert_eq!(c.capacity(), capacity);
+}
+
+#[test]
+fn test_clone_panic() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct PanicOnClone(usize);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            if self.0 == 3 {
+                panic!("clone panicked");
+            }
+            PanicOnClone(self.0)
+        }
+    }
+    impl Drop for PanicOnClone {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, AtomicOrdering::SeqCst);
+        }
+    }
+
+    let a: Vector<PanicOnClone> = (0..5).map(PanicOnClone).collect();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| a.clone())).is_err());
+    assert_eq!(DROPS.load(AtomicOrdering::SeqCst), 3);
+
+    let mut b: Vector<PanicOnClone> = (0..2).map(PanicOnClone).collect();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| b.clone_from(&a))).is_err());
+    assert_eq!(b.len(), 3);
+    drop(b);
+    drop(a);
+    assert_eq!(DROPS.load(AtomicOrdering::SeqCst), 13);
+}
+
+#[test]
+fn test_retain() {
+    let mut visited = Vec::new();
+    let mut a: Vector<String> = (0..10).map(|i| i.to_string()).collect();
+
+    a.retain(|x| {
+        visited.push(x.clone());
+        x.parse::<usize>().unwrap() % 3 != 0
+    });
+    assert_eq!(visited, (0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+    assert_eq!(a, ["1", "2", "4", "5", "7", "8"]);
+}
+
+#[test]
+fn test_retain_panic() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted(usize);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, AtomicOrdering::SeqCst);
+        }
+    }
+
+    let mut a: Vector<Counted> = (0..8).map(Counted).collect();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        a.retain(|x| {
+            if x.0 == 5 {
+                panic!("predicate panicked");
+            }
+            x.0 % 2 == 0
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(DROPS.load(AtomicOrdering::SeqCst), 2);
+    assert_eq!(a.iter().map(|x| x.0).collect::<Vec<_>>(), [0, 2, 4, 5, 6, 7]);
+
+    drop(a);
+    assert_eq!(DROPS.load(AtomicOrdering::SeqCst), 8);
+}
+
+#[test]
+fn test_from() {
+    let slice = ["a".to_string(), "b".to_string()];
+    let a = Vector::from(&slice[..]);
+    assert_eq!(a, slice);
+    assert_eq!(a.capacity(), 2);
+
+    let b = Vector::from([1, 2, 3]);
+    assert_eq!(b, [1, 2, 3]);
+
+    let boxed: Box<[usize]> = Box::new([1, 2, 3]);
+    let ptr = boxed.as_ptr();
+    let c = Vector::from(boxed);
+    assert_eq!(c.as_ptr(), ptr);
+    assert_eq!(c, b);
+
+    let vec = vec![1, 2, 3];
+    let ptr = vec.as_ptr();
+    let d = Vector::from(vec);
+    assert_eq!(d.as_ptr(), ptr);
+    let vec: Vec<usize> = d.into();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [1, 2, 3]);
+
+    let string = String::from("hello");
+    let ptr = string.as_ptr();
+    let e = Vector::from(string);
+    assert_eq!(e.as_ptr(), ptr);
+    assert_eq!(e, Vector::from("hello"));
+
+    let deque: VecDeque<usize> = (0..4).collect();
+    assert_eq!(Vector::from(deque), [0, 1, 2, 3]);
+
+    let borrowed: Cow<[usize]> = Cow::Borrowed(&[1, 2]);
+    let owned: Cow<[usize]> = Cow::Owned(vec![1, 2]);
+    assert_eq!(Vector::from(borrowed), Vector::from(owned));
+}
@@ -0,0 +1,178 @@
+#[macro_use]
+extern crate vector;
+
+
+use std::mem::MaybeUninit;
+
+use vector::Vector;
+
+
+#[test]
+fn test_len_push_pop_insert_remove() {
+    let mut v = Vector::new();
+    assert!(v.is_empty());
+
+    v.push(1);
+    v.push(3);
+    v.insert(1, 2);
+    v.insert(3, 4);
+    assert_eq!(v.len(), 4);
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    assert_eq!(v.remove(0), 1);
+    assert_eq!(v.pop(), Some(4));
+    assert_eq!(v, [2, 3]);
+
+    v.clear();
+    assert_eq!(v.pop(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_out_of_bounds() {
+    let mut v = vector![1];
+    v.insert(2, 2);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut v = vector![1, 2, 3, 4];
+    assert_eq!(v.swap_remove(1), 2);
+    assert_eq!(v, [1, 4, 3]);
+    assert_eq!(v.swap_remove(2), 3);
+    assert_eq!(v, [1, 4]);
+}
+
+#[test]
+fn test_dedup() {
+    let mut v = vector![1, 1, 2, 3, 3, 3, 2];
+    v.dedup();
+    assert_eq!(v, [1, 2, 3, 2]);
+
+    let mut v = vector![10, 11, 20, 21, 30, 20];
+    v.dedup_by_key(|i| *i / 10);
+    assert_eq!(v, [10, 20, 30, 20]);
+
+    let mut v = vector!["foo", "bar", "Bar", "baz", "bar"];
+    v.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(v, ["foo", "bar", "baz", "bar"]);
+
+    let mut v = vector![String::from("a"), String::from("a"), String::from("b")];
+    v.dedup();
+    assert_eq!(v, [String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn test_extend_from_slice_and_within() {
+    let mut v = vector![1, 2];
+    v.extend_from_slice(&[3, 4]);
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    let mut v = vector![String::from("a"), String::from("b"), String::from("c")];
+    v.extend_from_within(1..);
+    v.extend_from_within(..=1);
+    assert_eq!(v, ["a", "b", "c", "b", "c", "a", "b"]);
+}
+
+#[test]
+fn test_splice() {
+    let mut v = vector![1, 2, 3, 4, 5];
+    let removed = v.splice(2..4, [10, 11, 12]).collect::<Vec<_>>();
+    assert_eq!(removed, [3, 4]);
+    assert_eq!(v, [1, 2, 10, 11, 12, 5]);
+
+    let mut v = vector![1, 2, 3, 4, 5];
+    v.splice(1..4, Some(20));
+    assert_eq!(v, [1, 20, 5]);
+
+    let mut v = vector![1, 2, 3];
+    v.splice(1..2, (10..15).filter(|x| x % 2 == 0));
+    assert_eq!(v, [1, 10, 12, 14, 3]);
+
+    let mut v = vector![1, 2, 3];
+    v.splice(3.., [4, 5]);
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_retain_mut() {
+    let mut v = vector![1, 2, 3, 4];
+    v.retain_mut(|x| {
+        *x *= 10;
+        *x != 20
+    });
+    assert_eq!(v, [10, 30, 40]);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut v = (1..=10).collect::<Vector<_>>();
+    let evens = v.extract_if(.., |x| *x % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(evens, [2, 4, 6, 8, 10]);
+    assert_eq!(v, [1, 3, 5, 7, 9]);
+
+    let mut v = (1..=10).collect::<Vector<_>>();
+    let some = v.extract_if(2..8, |x| *x % 3 == 0).collect::<Vec<_>>();
+    assert_eq!(some, [3, 6]);
+    assert_eq!(v, [1, 2, 4, 5, 7, 8, 9, 10]);
+
+    let mut v = (1..=6).collect::<Vector<_>>();
+    {
+        let mut iter = v.drain_filter(|x| *x > 2);
+        assert_eq!(iter.next(), Some(3));
+    }
+    assert_eq!(v, [1, 2, 4, 5, 6]);
+}
+
+#[test]
+fn test_leak() {
+    let v = vector![1, 2, 3];
+    let slice: &'static mut [i32] = v.leak();
+    slice[0] = 10;
+    assert_eq!(slice, [10, 2, 3]);
+    unsafe {
+        drop(Vector::from_raw_parts(slice.as_mut_ptr(), 3, 3));
+    }
+}
+
+#[test]
+fn test_spare_capacity() {
+    let mut v = Vector::<u32>::with_capacity(10);
+    v.push(0);
+
+    let spare = v.spare_capacity_mut();
+    assert_eq!(spare.len(), 9);
+    spare[0] = MaybeUninit::new(1);
+    spare[1] = MaybeUninit::new(2);
+    unsafe {
+        v.set_len(3);
+    }
+    assert_eq!(v, [0, 1, 2]);
+
+    let (init, spare) = v.split_at_spare_mut();
+    assert_eq!(init, [0, 1, 2]);
+    assert_eq!(spare.len(), 7);
+}
+
+#[test]
+fn test_raw_parts() {
+    let mut v = vector![1, 2, 3];
+    let ptr = v.as_mut_ptr();
+    assert_eq!(v.as_ptr(), ptr as *const _);
+
+    let (raw, len, cap) = v.into_raw_parts();
+    assert_eq!(raw, ptr);
+    assert_eq!(len, 3);
+
+    let v = unsafe { Vector::from_raw_parts(raw, len, cap) };
+    assert_eq!(v, [1, 2, 3]);
+}
+
+#[test]
+fn test_push_within_capacity() {
+    let mut v = Vector::with_capacity(2);
+    assert_eq!(v.push_within_capacity(1), Ok(()));
+    assert_eq!(v.push_within_capacity(2), Ok(()));
+    assert_eq!(v.push_within_capacity(3), Err(3));
+    assert_eq!(v, [1, 2]);
+}
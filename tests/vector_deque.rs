@@ -0,0 +1,110 @@
+extern crate vector;
+extern crate collection_traits;
+
+
+use vector::{Vector, VectorDeque};
+use collection_traits::*;
+
+
+const SIZE: usize = 32;
+
+
+#[test]
+fn test_deque_ends() {
+    let mut d = VectorDeque::new();
+
+    for i in 0..SIZE {
+        d.push_back(i);
+        d.push_front(i);
+    }
+    assert_eq!(d.len(), SIZE * 2);
+    assert_eq!(d.front(), Some(&(SIZE - 1)));
+    assert_eq!(d.back(), Some(&(SIZE - 1)));
+
+    for i in (0..SIZE).rev() {
+        assert_eq!(d.pop_front(), Some(i));
+        assert_eq!(d.pop_back(), Some(i));
+    }
+    assert_eq!(d.pop_front(), None);
+    assert_eq!(d.pop_back(), None);
+}
+
+#[test]
+fn test_deque_wrap_and_grow() {
+    let mut d = VectorDeque::with_capacity(4);
+
+    for i in 0..3 {
+        d.push_back(i);
+    }
+    d.pop_front();
+    d.pop_front();
+    for i in 3..SIZE {
+        d.push_back(i);
+    }
+    for i in 2..SIZE {
+        assert_eq!(d[i - 2], i);
+    }
+
+    let (front, back) = d.as_slices();
+    assert_eq!(front.len() + back.len(), SIZE - 2);
+    assert_eq!(d.iter().cloned().collect::<Vector<_>>(), (2..SIZE).collect::<Vector<_>>());
+}
+
+#[test]
+fn test_deque_insert_remove() {
+    let mut d: VectorDeque<usize> = (0..8).collect();
+
+    d.insert(2, 100);
+    d.insert(7, 200);
+    assert_eq!(d.iter().cloned().collect::<Vector<_>>(), [0, 1, 100, 2, 3, 4, 5, 200, 6, 7]);
+    assert_eq!(d.remove(7), 200);
+    assert_eq!(d.remove(2), 100);
+    assert_eq!(d.iter().cloned().collect::<Vector<_>>(), [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_deque_traits() {
+    let mut d = VectorDeque::new();
+
+    d.enqueue(1);
+    d.enqueue(2);
+    assert_eq!(d.peek(), Some(&1));
+    assert_eq!(d.dequeue(), Some(1));
+
+    d.push(3);
+    assert_eq!(d.top(), Some(&3));
+    assert_eq!(d.pop(), Some(3));
+    assert_eq!(d.len(), 1);
+}
+
+#[test]
+fn test_deque_vector_conversion() {
+    let vector: Vector<usize> = (0..SIZE).collect();
+    let ptr = vector.as_ptr();
+
+    let mut d = VectorDeque::from(vector);
+    d.pop_front();
+    d.pop_front();
+    d.push_back(SIZE);
+    d.push_back(SIZE + 1);
+    assert_eq!(d.capacity(), SIZE);
+
+    let vector = Vector::from(d);
+    assert_eq!(vector.as_ptr(), ptr);
+    assert_eq!(vector, (2..SIZE + 2).collect::<Vector<_>>());
+}
+
+#[test]
+fn test_vector_deque_orientation() {
+    let mut v = Vector::new();
+
+    v.push_back(1);
+    v.push_back(2);
+    v.push_front(0);
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(v.front(), Some(&0));
+    assert_eq!(v.back(), Some(&2));
+    assert_eq!(v.pop_front(), Some(0));
+    assert_eq!(v.pop_back(), Some(2));
+    assert_eq!(v.top(), Some(&1));
+}
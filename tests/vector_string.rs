@@ -0,0 +1,127 @@
+extern crate vector;
+
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use vector::{Vector, VectorString};
+
+
+#[test]
+fn test_push_insert_remove() {
+    let mut s = VectorString::new();
+    s.push('a');
+    s.push('é');
+    s.push_str("日本");
+    s.insert(1, 'ß');
+    s.insert_str(0, ">>");
+    assert_eq!(s, ">>aßé日本");
+    assert_eq!(s.len(), 13);
+
+    assert_eq!(s.remove(3), 'ß');
+    assert_eq!(s.pop(), Some('本'));
+    assert_eq!(s, ">>aé日");
+    assert_eq!(s.chars().count(), 5);
+
+    s.truncate(5);
+    assert_eq!(s, ">>aé");
+    s.truncate(100);
+    assert_eq!(s.split_off(2), "aé");
+    assert_eq!(s, ">>");
+
+    let mut s = VectorString::from("a1b2c3ü4");
+    s.retain(|ch| !ch.is_ascii_digit());
+    assert_eq!(s, "abcü");
+}
+
+#[test]
+#[should_panic(expected = "is not a char boundary")]
+fn test_insert_not_char_boundary() {
+    let mut s = VectorString::from("é");
+    s.insert(1, 'a');
+}
+
+#[test]
+#[should_panic(expected = "is not a char boundary")]
+fn test_truncate_not_char_boundary() {
+    let mut s = VectorString::from("日本");
+    s.truncate(4);
+}
+
+#[test]
+fn test_drain() {
+    let mut s = VectorString::from("αβγδε");
+
+    let drained: String = s.drain(2..6).collect();
+    assert_eq!(drained, "βγ");
+    assert_eq!(s, "αδε");
+
+    let mut drain = s.drain(2..);
+    assert_eq!(drain.next_back(), Some('ε'));
+    assert_eq!(drain.as_str(), "δ");
+    drop(drain);
+    assert_eq!(s, "α");
+
+    s.drain(..);
+    assert!(s.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "is not a char boundary")]
+fn test_drain_not_char_boundary() {
+    let mut s = VectorString::from("αβ");
+    s.drain(1..);
+}
+
+#[test]
+fn test_utf8() {
+    let s = VectorString::from_utf8(Vector::from("ok")).unwrap();
+    assert_eq!(s, "ok");
+
+    let bytes = Vector::from(&b"ab\xffcd"[..]);
+    let error = VectorString::from_utf8(bytes.clone()).unwrap_err();
+    assert_eq!(error.utf8_error().valid_up_to(), 2);
+    assert_eq!(error.as_bytes(), &bytes[..]);
+    assert_eq!(error.into_bytes(), bytes);
+
+    assert_eq!(VectorString::from_utf8_lossy(b"ab\xffcd"), "ab\u{fffd}cd");
+    assert_eq!(VectorString::from_utf8_lossy(b"plain"), "plain");
+}
+
+#[test]
+fn test_conversions() {
+    let string = String::with_capacity(64) + "héllo";
+    let ptr = string.as_ptr();
+
+    let s = VectorString::from(string);
+    assert_eq!(s.as_ptr(), ptr);
+    assert_eq!(s.capacity(), 64);
+
+    let string = String::from(s);
+    assert_eq!(string.as_ptr(), ptr);
+    assert_eq!(string, "héllo");
+
+    let s: VectorString = "a-b-c".split('-').collect();
+    assert_eq!(s, "abc");
+    let s: VectorString = ['x', 'y'].iter().collect();
+    assert_eq!(&*s.to_uppercase(), "XY");
+    assert_eq!(VectorString::from(Cow::Borrowed("cow")), "cow");
+    assert_eq!(&*VectorString::from('∂').into_boxed_str(), "∂");
+    assert_eq!(VectorString::from("bytes").into_bytes(), &b"bytes"[..]);
+}
+
+#[test]
+fn test_fmt() {
+    let mut s = VectorString::new();
+    let id = "id";
+    write!(s, "{}-{:02}", id, 7).unwrap();
+    s.write_char('✓').unwrap();
+
+    assert_eq!(s, "id-07✓");
+    assert_eq!(format!("{}", s), "id-07✓");
+    assert_eq!(format!("{:?}", s), "\"id-07✓\"");
+    assert!(s.starts_with("id"));
+    let mut strings = vec![VectorString::from("b"), VectorString::from("a")];
+    strings.sort();
+    assert_eq!(strings, ["a", "b"]);
+}
@@ -0,0 +1,203 @@
+extern crate vector;
+
+
+use std::cell::Cell;
+
+use vector::Vector;
+
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Zst;
+
+impl Drop for Zst {
+    fn drop(&mut self) {
+        DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+fn drops() -> usize {
+    DROPS.with(|drops| drops.replace(0))
+}
+
+fn zsts(n: usize) -> Vector<Zst> {
+    let v = (0..n).map(|_| Zst).collect();
+    drops();
+    v
+}
+
+
+#[test]
+fn test_capacity() {
+    let mut v = Vector::<()>::new();
+    assert_eq!(v.capacity(), usize::MAX);
+    assert_eq!(Vector::<()>::with_capacity(10).capacity(), usize::MAX);
+
+    v.reserve(usize::MAX);
+    v.reserve_exact(usize::MAX);
+    v.shrink_to_fit();
+    v.shrink_to(0);
+    assert_eq!(v.capacity(), usize::MAX);
+    assert_eq!(v.spare_capacity_mut().len(), usize::MAX);
+
+    unsafe {
+        v.set_len(usize::MAX);
+    }
+    assert_eq!(v.len(), usize::MAX);
+    assert!(v.try_reserve(1).is_err());
+    assert!(v.try_push(()).is_err());
+    assert_eq!(v.push_within_capacity(()), Err(()));
+    assert_eq!(v.pop(), Some(()));
+    assert_eq!(v.len(), usize::MAX - 1);
+    v.push(());
+
+    let mut iter = v.into_iter();
+    assert_eq!(iter.size_hint(), (usize::MAX, Some(usize::MAX)));
+    assert_eq!(iter.next(), Some(()));
+    assert_eq!(iter.next_back(), Some(()));
+    assert_eq!(iter.len(), usize::MAX - 2);
+    assert_eq!(iter.as_slice().len(), usize::MAX - 2);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_push_overflow() {
+    let mut v = Vector::<()>::new();
+    unsafe {
+        v.set_len(usize::MAX);
+    }
+    v.push(());
+}
+
+#[test]
+fn test_mutation() {
+    let mut v = zsts(4);
+
+    v.push(Zst);
+    v.insert(0, Zst);
+    v.insert(6, Zst);
+    assert_eq!(v.len(), 7);
+    assert_eq!(drops(), 0);
+
+    assert_eq!(v.pop(), Some(Zst));
+    assert_eq!(v.remove(3), Zst);
+    assert_eq!(v.swap_remove(0), Zst);
+    assert_eq!(drops(), 6);
+    assert_eq!(v.len(), 4);
+
+    v.truncate(3);
+    assert_eq!(drops(), 1);
+    v.resize(5, Zst);
+    assert_eq!(drops(), 0);
+    v.resize(2, Zst);
+    assert_eq!(drops(), 4);
+
+    let mut n = 0;
+    v.retain(|_| {
+        n += 1;
+        n % 2 == 0
+    });
+    assert_eq!((v.len(), drops()), (1, 1));
+
+    v.extend_from_slice(&[Zst, Zst]);
+    assert_eq!(drops(), 2);
+    v.extend_from_within(1..);
+    v.dedup();
+    assert_eq!((v.len(), drops()), (1, 4));
+
+    let mut other = v.split_off(0);
+    assert_eq!((v.len(), other.len()), (0, 1));
+    other.extend(vec![Zst, Zst]);
+    v.append(&mut other);
+    assert_eq!((v.len(), other.len(), drops()), (3, 0, 0));
+
+    v.clear();
+    assert_eq!((v.len(), drops()), (0, 3));
+}
+
+#[test]
+fn test_into_iter() {
+    let mut iter = zsts(5).into_iter();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert!(iter.next().is_some());
+    assert!(iter.next_back().is_some());
+    assert_eq!(drops(), 2);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.as_slice().len(), 3);
+    assert_eq!(iter.as_mut_slice().len(), 3);
+    drop(iter);
+    assert_eq!(drops(), 3);
+
+    assert_eq!(zsts(4).into_iter().rev().count(), 4);
+    assert_eq!(drops(), 4);
+
+    let mut iter = zsts(2).into_iter();
+    assert!(iter.next().is_some());
+    assert!(iter.next().is_some());
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    assert_eq!(iter.len(), 0);
+    drop(iter);
+    assert_eq!(drops(), 2);
+}
+
+#[test]
+fn test_drain() {
+    let mut v = zsts(6);
+
+    let mut drain = v.drain(1..4);
+    assert_eq!(drain.len(), 3);
+    assert!(drain.next().is_some());
+    assert!(drain.next_back().is_some());
+    assert_eq!(drain.as_slice().len(), 1);
+    drop(drain);
+    assert_eq!((v.len(), drops()), (3, 3));
+
+    let mut drain = v.drain(..2);
+    assert!(drain.next().is_some());
+    drain.keep_rest();
+    assert_eq!((v.len(), drops()), (2, 1));
+
+    let removed = v.splice(..1, vec![Zst, Zst, Zst]).count();
+    assert_eq!((removed, v.len(), drops()), (1, 4, 1));
+
+    let mut n = 0;
+    let extracted = v.extract_if(.., |_| {
+        n += 1;
+        n % 2 == 1
+    }).count();
+    assert_eq!((extracted, v.len(), drops()), (2, 2, 2));
+
+    drop(v);
+    assert_eq!(drops(), 2);
+}
+
+#[test]
+fn test_conversions() {
+    let v = Vector::from(&[Zst, Zst, Zst][..]);
+    assert_eq!(drops(), 3);
+    assert_eq!(v.clone().len(), 3);
+    assert_eq!(drops(), 3);
+
+    let boxed = v.clone().into_boxed_slice();
+    assert_eq!(boxed.len(), 3);
+    drop(boxed);
+    assert_eq!(drops(), 3);
+
+    let (ptr, len, cap) = v.into_raw_parts();
+    assert_eq!((len, cap), (3, usize::MAX));
+    let v = unsafe { Vector::from_raw_parts(ptr, len, cap) };
+    assert_eq!(v.len(), 3);
+    assert_eq!(drops(), 0);
+
+    let leaked: &mut [Zst] = v.leak();
+    assert_eq!(leaked.len(), 3);
+    assert_eq!(drops(), 0);
+
+    let v: Vector<()> = (0..1000).map(|_| ()).collect();
+    assert_eq!(v.len(), 1000);
+    assert_eq!(v, &[(); 1000][..]);
+}